@@ -0,0 +1,193 @@
+use compound::*;
+use fitness::*;
+use mold::*;
+use optimizer::*;
+use primes::*;
+use randomizer::*;
+use rule::*;
+
+pub struct SimulatedAnnealing<T: Randomizer> {
+    pub randomizer: T,
+    pub iterations: usize,
+    pub initial_temperature: f64,
+    pub final_temperature: f64,
+    pub primes: Vec<Prime<'static>>,
+    pub fitness: Box<dyn Fitness>,
+}
+
+enum Move {
+    Add(usize, usize),
+    Remove(usize, usize, Prime<'static>),
+    Swap(usize, usize, Prime<'static>),
+    Nudge(usize, usize, usize, usize),
+}
+
+impl<T: Randomizer> Optimizer for SimulatedAnnealing<T> {
+    fn optimize(&mut self, mold: Mold) -> Compound {
+        let mut pattern = Compound::new(mold.font_size);
+        let targets = targets(&mold);
+        if targets.is_empty() {
+            return pattern;
+        }
+
+        let mut score = self.fitness.score(&mold, &pattern);
+        let mut best = pattern.clone();
+        let mut best_score = score;
+        for i in 0..self.iterations {
+            let m = match self.propose(&mold, &mut pattern, &targets) {
+                Some(m) => m,
+                None => continue,
+            };
+            let new_score = self.fitness.score(&mold, &pattern);
+            let delta = new_score - score;
+            if delta >= 0.0 || self.randomizer.random_number() < (delta / self.temperature(i)).exp() {
+                score = new_score;
+                if best_score < score {
+                    best = pattern.clone();
+                    best_score = score;
+                }
+            } else {
+                undo(&mut pattern, m);
+            }
+        }
+        best
+    }
+
+    fn set_fitness(&mut self, fitness: Box<dyn Fitness>) {
+        self.fitness = fitness;
+    }
+
+    fn set_primes(&mut self, primes: Vec<Prime<'static>>) {
+        self.primes = primes;
+    }
+}
+
+impl<T: Randomizer> SimulatedAnnealing<T> {
+    pub fn new(randomizer: T, iterations: usize, rule: &Rule) -> Result<Self, String> {
+        Ok(SimulatedAnnealing {
+            randomizer: randomizer,
+            iterations: iterations,
+            initial_temperature: 2.0,
+            final_temperature: 0.05,
+            primes: stable_primes(rule)?,
+            fitness: default_fitness(),
+        })
+    }
+
+    // Cools down geometrically from the initial to the final temperature.
+    fn temperature(&self, i: usize) -> f64 {
+        let progress = i as f64 / self.iterations as f64;
+        self.initial_temperature * (self.final_temperature / self.initial_temperature).powf(progress)
+    }
+
+    fn random_index(&mut self, len: usize) -> usize {
+        ((self.randomizer.random_number() * len as f64) as usize).min(len - 1)
+    }
+
+    fn propose(&mut self, mold: &Mold, pattern: &mut Compound, targets: &[(usize, usize)]) -> Option<Move> {
+        let placements = pattern.placements();
+        let r = self.randomizer.random_number();
+
+        if placements.is_empty() || r < 0.4 {
+            let (tx, ty) = targets[self.random_index(targets.len())];
+            let (p, x, y) = place_near(&mut self.randomizer, &self.primes, mold, tx, ty);
+            if !pattern.fits(&p, x, y) {
+                return None;
+            }
+            pattern.set(p, x, y);
+            return Some(Move::Add(x, y));
+        }
+
+        let (x, y, p) = placements[self.random_index(placements.len())].clone();
+        pattern.remove(x, y);
+        if r < 0.6 {
+            return Some(Move::Remove(x, y, p));
+        }
+
+        if r < 0.8 {
+            let q = fitting_prime(&mut self.randomizer, &self.primes, mold, |_| (x, y));
+            if q != p && pattern.fits(&q, x, y) {
+                pattern.set(q, x, y);
+                return Some(Move::Swap(x, y, p));
+            }
+        } else {
+            let (nx, ny) = match self.random_index(4) {
+                0 => (x + 1, y),
+                1 => (x, y + 1),
+                2 => (x.wrapping_sub(1), y),
+                _ => (x, y.wrapping_sub(1)),
+            };
+            if nx < pattern.font_size && ny < pattern.font_size && pattern.fits(&p, nx, ny) {
+                pattern.set(p, nx, ny);
+                return Some(Move::Nudge(x, y, nx, ny));
+            }
+        }
+
+        pattern.set(p, x, y);
+        None
+    }
+}
+
+fn undo(pattern: &mut Compound, m: Move) {
+    match m {
+        Move::Add(x, y) => pattern.remove(x, y),
+        Move::Remove(x, y, p) => pattern.set(p, x, y),
+        Move::Swap(x, y, p) => pattern.set(p, x, y),
+        Move::Nudge(x, y, nx, ny) => {
+            let p = pattern.pattern[nx][ny].clone();
+            pattern.remove(nx, ny);
+            pattern.set(p, x, y);
+        },
+    }
+}
+
+#[test]
+fn test_optimize_is_reproducible() {
+    let optimize = |seed| {
+        let mut optimizer = SimulatedAnnealing::new(SplitMix::from_seed(seed), 500, &Rule::conway()).unwrap();
+        optimizer.optimize(Mold::from_char('R', 30))
+    };
+
+    assert_eq!(optimize(7), optimize(7));
+    assert_ne!(optimize(7), optimize(8));
+}
+
+#[test]
+fn test_optimize_places_primes_that_fit() {
+    for seed in 0..4 {
+        for c in "AgW8x".chars() {
+            let mut optimizer = SimulatedAnnealing::new(SplitMix::from_seed(seed), 500, &Rule::conway()).unwrap();
+            let compound = optimizer.optimize(Mold::from_char(c, 30));
+
+            let mut rebuilt = Compound::new(compound.font_size);
+            for (x, y, p) in compound.placements() {
+                assert!(rebuilt.fits(&p, x, y));
+                rebuilt.set(p, x, y);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_optimize_beats_gradient_descent() {
+    let mut annealing_total = 0.0;
+    let mut gradient_total = 0.0;
+    for seed in 0..4 {
+        for c in "AgW8x".chars() {
+            let mold = Mold::from_char(c, 30);
+            let mut annealing = SimulatedAnnealing::new(SplitMix::from_seed(seed), 2000, &Rule::conway()).unwrap();
+            let mut gradient = GradientDescent::new(SplitMix::from_seed(seed), 5, &Rule::conway()).unwrap();
+            annealing_total += evaluate(&mold, &annealing.optimize(Mold::from_char(c, 30)));
+            gradient_total += evaluate(&mold, &gradient.optimize(Mold::from_char(c, 30)));
+        }
+    }
+
+    assert!(annealing_total > gradient_total, "{} <= {}", annealing_total, gradient_total);
+}
+
+#[test]
+fn test_optimize_empty_mold() {
+    let mut optimizer = SimulatedAnnealing::new(SplitMix::from_seed(0), 100, &Rule::conway()).unwrap();
+
+    assert!(optimizer.optimize(Mold::from_char(' ', 20)).placements().is_empty());
+}
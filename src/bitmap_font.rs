@@ -0,0 +1,389 @@
+use std::collections::HashMap;
+use std::str;
+use cells::*;
+use mold::*;
+use resample::*;
+
+const PSF1_MAGIC: [u8; 2] = [0x36, 0x04];
+const PSF2_MAGIC: [u8; 4] = [0x72, 0xb5, 0x4a, 0x86];
+// Fonts with larger glyphs or offsets, in cells, are rejected rather than
+// allocated.
+const MAX_GLYPH_SIZE: usize = 256;
+
+// A font loaded from a BDF or PSF file. Every glyph is drawn on a square of
+// `size` cells from the left edge, with the glyphs positioned on a common
+// baseline and the font's bounding box centred vertically in the square.
+pub struct BitmapFont {
+    pub size: usize,
+    pub glyphs: HashMap<char, Cells>,
+    // How far the next glyph starts, in cells of the glyphs.
+    pub advances: HashMap<char, usize>,
+}
+
+impl BitmapFont {
+    pub fn from_bytes(bytes: &[u8]) -> Result<BitmapFont, String> {
+        if bytes.starts_with(&PSF1_MAGIC) || bytes.starts_with(&PSF2_MAGIC) {
+            return BitmapFont::from_psf(bytes);
+        }
+        match str::from_utf8(bytes) {
+            Ok(s) if s.trim_start().starts_with("STARTFONT") => BitmapFont::from_bdf(s),
+            _ => Err("unknown font format, expected BDF or PSF".to_string()),
+        }
+    }
+
+    pub fn glyph(&self, c: char) -> Option<&Cells> {
+        self.glyphs.get(&c)
+    }
+
+    pub fn from_bdf(s: &str) -> Result<BitmapFont, String> {
+        let mut bounds = None;
+        let mut glyphs = Vec::new();
+        let mut encoding = None;
+        let mut bbx = None;
+        let mut dwidth = None;
+        let mut bitmap: Option<Vec<Vec<u8>>> = None;
+
+        for line in s.lines() {
+            let mut words = line.split_whitespace();
+            let keyword = match words.next() {
+                Some(keyword) => keyword,
+                None => continue,
+            };
+            let numbers: Vec<i64> = words.filter_map(|w| w.parse().ok()).collect();
+            if let Some(ref mut rows) = bitmap {
+                if keyword != "ENDCHAR" {
+                    rows.push(parse_hex(keyword)?);
+                    continue;
+                }
+            }
+
+            match keyword {
+                "FONTBOUNDINGBOX" => bounds = Some(bdf_box(&numbers)?),
+                "STARTCHAR" => {
+                    encoding = None;
+                    bbx = None;
+                    dwidth = None;
+                },
+                "ENCODING" => encoding = numbers.first().cloned(),
+                "BBX" => bbx = Some(bdf_box(&numbers)?),
+                "DWIDTH" => dwidth = Some(bdf_width(&numbers)?),
+                "BITMAP" => bitmap = Some(Vec::new()),
+                "ENDCHAR" => {
+                    let rows = bitmap.take().unwrap_or_default();
+                    // Glyphs without a Unicode encoding have an encoding of -1.
+                    let c = encoding.and_then(|e| if e < 0 { None } else { ::std::char::from_u32(e as u32) });
+                    if let (Some(c), Some(bbx)) = (c, bbx) {
+                        glyphs.push((c, bbx, dwidth, rows));
+                    }
+                },
+                _ => {},
+            }
+        }
+
+        let (width, height, left, bottom) = bounds.ok_or("BDF font has no FONTBOUNDINGBOX")?;
+        if width == 0 || height == 0 {
+            return Err(format!("unsupported BDF bounding box: {}x{}", width, height));
+        }
+        let size = width.max(height) as usize;
+        let pad_y = (size - height as usize) / 2;
+        let mut font = BitmapFont {
+            size: size,
+            glyphs: HashMap::new(),
+            advances: HashMap::new(),
+        };
+        for (c, (w, h, x_offset, y_offset), dwidth, rows) in glyphs {
+            let mut cells = Cells::new(size, size);
+            // Rows count down from the top of the bounding box, while BDF
+            // offsets count up from the baseline.
+            let x0 = x_offset - left;
+            let y0 = pad_y as i64 + height + bottom - y_offset - h;
+            for (y, row) in rows.iter().enumerate().take(h as usize) {
+                for x in 0..w as usize {
+                    if bit(row, x) {
+                        set(&mut cells, x0 + x as i64, y0 + y as i64);
+                    }
+                }
+            }
+            font.glyphs.insert(c, cells);
+            font.advances.insert(c, dwidth.unwrap_or(width) as usize);
+        }
+        Ok(font)
+    }
+
+    // Reads PSF1 and PSF2 console fonts. Without a Unicode table, glyph n is
+    // taken to be the character with code point n.
+    pub fn from_psf(bytes: &[u8]) -> Result<BitmapFont, String> {
+        let (count, width, height, glyph_size, start, has_table) = if bytes.starts_with(&PSF1_MAGIC) {
+            let mode = *bytes.get(2).ok_or("truncated PSF header")?;
+            let height = *bytes.get(3).ok_or("truncated PSF header")? as usize;
+            let count = if mode & 0x01 != 0 { 512 } else { 256 };
+            (count, 8, height, height, 4, mode & 0x06 != 0)
+        } else {
+            if bytes.len() < 32 {
+                return Err("truncated PSF header".to_string());
+            }
+            let word = |i: usize| {
+                bytes[i] as usize | (bytes[i + 1] as usize) << 8 | (bytes[i + 2] as usize) << 16 | (bytes[i + 3] as usize) << 24
+            };
+            (word(16), word(28), word(24), word(20), word(8), word(12) & 0x01 != 0)
+        };
+
+        if width == 0 || height == 0 || width > MAX_GLYPH_SIZE || height > MAX_GLYPH_SIZE || glyph_size == 0 {
+            return Err(format!("unsupported PSF glyph size: {}x{}", width, height));
+        }
+        let end = count.checked_mul(glyph_size).and_then(|n| n.checked_add(start));
+        let end = match end {
+            Some(end) if end <= bytes.len() => end,
+            _ => return Err("truncated PSF font".to_string()),
+        };
+        let row_size = width.div_ceil(8);
+        let size = width.max(height);
+        let pad_y = (size - height) / 2;
+        let mut bitmaps = Vec::new();
+        for i in 0..count {
+            let glyph = &bytes[start + i * glyph_size..start + (i + 1) * glyph_size];
+            let mut cells = Cells::new(size, size);
+            for y in 0..height.min(glyph_size / row_size) {
+                let row = &glyph[y * row_size..(y + 1) * row_size];
+                for x in 0..width {
+                    if bit(row, x) {
+                        cells.make_alive(x, pad_y + y);
+                    }
+                }
+            }
+            bitmaps.push(cells);
+        }
+
+        let chars: Vec<Vec<char>> = if !has_table {
+            (0..count).map(|i| ::std::char::from_u32(i as u32).into_iter().collect()).collect()
+        } else if bytes.starts_with(&PSF1_MAGIC) {
+            psf1_table(&bytes[end..], count)
+        } else {
+            psf2_table(&bytes[end..], count)
+        };
+
+        let mut font = BitmapFont {
+            size: size,
+            glyphs: HashMap::new(),
+            advances: HashMap::new(),
+        };
+        for (cells, chars) in bitmaps.into_iter().zip(chars) {
+            for c in chars {
+                font.glyphs.entry(c).or_insert_with(|| cells.clone());
+                font.advances.insert(c, width);
+            }
+        }
+        Ok(font)
+    }
+}
+
+// Characters missing from the font are left blank and as wide as a space.
+impl Typeface for BitmapFont {
+    fn mold(&self, c: char, font_size: usize, resampling: Resampling) -> Mold {
+        match self.glyph(c) {
+            Some(cells) => Mold::from_cells(cells, font_size, resampling),
+            None => Mold::from_cells(&Cells::new(self.size, self.size), font_size, resampling),
+        }
+    }
+
+    fn advance(&self, c: char, _: Option<char>, font_size: usize) -> usize {
+        let width = self.advances.get(&c)
+            .or_else(|| self.advances.get(&' '))
+            .cloned()
+            .unwrap_or(self.size / 3);
+        match self.size {
+            0 => 0,
+            size => (width * font_size + size / 2) / size,
+        }
+    }
+}
+
+fn bdf_box(numbers: &[i64]) -> Result<(i64, i64, i64, i64), String> {
+    let max = MAX_GLYPH_SIZE as i64;
+    match *numbers {
+        [w, h, x, y] if (0..=max).contains(&w) && (0..=max).contains(&h) && x.abs() <= max && y.abs() <= max => Ok((w, h, x, y)),
+        _ => Err("invalid bounding box in BDF font".to_string()),
+    }
+}
+
+// Advances wider than any glyph could be would overflow when scaled up to
+// the font size.
+fn bdf_width(numbers: &[i64]) -> Result<i64, String> {
+    match numbers.first() {
+        Some(&w) if (0..=MAX_GLYPH_SIZE as i64).contains(&w) => Ok(w),
+        _ => Err("invalid DWIDTH in BDF font".to_string()),
+    }
+}
+
+fn parse_hex(s: &str) -> Result<Vec<u8>, String> {
+    s.as_bytes().chunks(2)
+        .filter(|pair| pair.len() == 2)
+        .map(|pair| str::from_utf8(pair).ok().and_then(|hex| u8::from_str_radix(hex, 16).ok()))
+        .collect::<Option<Vec<u8>>>()
+        .ok_or_else(|| format!("invalid bitmap row in BDF font: {}", s))
+}
+
+// The x-th pixel of a row of bytes, most significant bit first.
+fn bit(row: &[u8], x: usize) -> bool {
+    row.get(x / 8).is_some_and(|byte| byte & (0x80 >> (x % 8)) != 0)
+}
+
+fn set(cells: &mut Cells, x: i64, y: i64) {
+    if x >= 0 && y >= 0 && (x as usize) < cells.size_x && (y as usize) < cells.size_y {
+        cells.make_alive(x as usize, y as usize);
+    }
+}
+
+// The characters of each glyph as UCS-2 values ending in 0xFFFF. Sequences of
+// combining characters after 0xFFFE are skipped.
+fn psf1_table(bytes: &[u8], count: usize) -> Vec<Vec<char>> {
+    let mut table = vec![Vec::new(); count];
+    let mut glyph = 0;
+    let mut in_sequence = false;
+    for pair in bytes.chunks(2) {
+        if glyph == count || pair.len() < 2 {
+            break;
+        }
+        match pair[0] as u32 | (pair[1] as u32) << 8 {
+            0xffff => {
+                glyph += 1;
+                in_sequence = false;
+            },
+            0xfffe => in_sequence = true,
+            u if !in_sequence => table[glyph].extend(::std::char::from_u32(u)),
+            _ => {},
+        }
+    }
+    table
+}
+
+// The characters of each glyph in UTF-8 ending in 0xFF. Sequences after 0xFE
+// are skipped.
+fn psf2_table(bytes: &[u8], count: usize) -> Vec<Vec<char>> {
+    bytes.split(|&b| b == 0xff)
+        .take(count)
+        .map(|entry| {
+            let single = entry.split(|&b| b == 0xfe).next().unwrap_or(&[]);
+            String::from_utf8_lossy(single).chars().filter(|&c| c != '\u{fffd}').collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+const BDF: &str = "STARTFONT 2.1
+FONT -test-fixed-medium-r-normal--6-60-75-75-c-40-iso10646-1
+SIZE 6 75 75
+FONTBOUNDINGBOX 4 6 0 -1
+STARTPROPERTIES 1
+FONT_ASCENT 5
+ENDPROPERTIES
+CHARS 3
+STARTCHAR exclam
+ENCODING 33
+DWIDTH 4 0
+BBX 1 5 1 0
+BITMAP
+80
+80
+80
+00
+80
+ENDCHAR
+STARTCHAR comma
+ENCODING 44
+DWIDTH 4 0
+BBX 2 2 0 -1
+BITMAP
+40
+80
+ENDCHAR
+STARTCHAR unencoded
+ENCODING -1
+BBX 1 1 0 0
+BITMAP
+80
+ENDCHAR
+ENDFONT
+";
+
+#[cfg(test)]
+fn alive(cells: &Cells) -> Vec<(usize, usize)> {
+    let mut alive = Vec::new();
+    for y in 0..cells.size_y {
+        for x in 0..cells.size_x {
+            if cells.is_alive(x, y) {
+                alive.push((x, y));
+            }
+        }
+    }
+    alive
+}
+
+#[test]
+fn test_from_bdf() {
+    let font = BitmapFont::from_bytes(BDF.as_bytes()).unwrap();
+
+    assert_eq!(6, font.size);
+    assert_eq!(2, font.glyphs.len());
+    assert_eq!(vec![(1, 0), (1, 1), (1, 2), (1, 4)], alive(font.glyph('!').unwrap()));
+    assert_eq!(vec![(1, 4), (0, 5)], alive(font.glyph(',').unwrap()));
+    assert!(font.glyph('?').is_none());
+    assert_eq!(4, font.advances[&'!']);
+    assert_eq!(8, font.advance('!', None, 12));
+    assert_eq!(2, font.advance('?', None, 6));
+}
+
+#[test]
+fn test_from_psf() {
+    // A PSF2 font of two 3x4 glyphs with a Unicode table.
+    let mut bytes = vec![0x72, 0xb5, 0x4a, 0x86, 0, 0, 0, 0, 32, 0, 0, 0, 1, 0, 0, 0,
+        2, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 3, 0, 0, 0];
+    bytes.extend(&[0x40, 0x40, 0x00, 0x40, 0xe0, 0x00, 0xe0, 0x00]);
+    bytes.extend("!".as_bytes());
+    bytes.push(0xff);
+    bytes.extend("=\u{2550}".as_bytes());
+    bytes.push(0xff);
+    let font = BitmapFont::from_bytes(&bytes).unwrap();
+
+    assert_eq!(4, font.size);
+    assert_eq!(vec![(1, 0), (1, 1), (1, 3)], alive(font.glyph('!').unwrap()));
+    assert_eq!(font.glyph('='), font.glyph('\u{2550}'));
+    assert_eq!(6, alive(font.glyph('=').unwrap()).len());
+
+    // A PSF1 font of 256 8x2 glyphs without a table.
+    let mut bytes = vec![0x36, 0x04, 0, 2];
+    for i in 0..256 {
+        bytes.extend(&[i as u8, 0]);
+    }
+    let font = BitmapFont::from_bytes(&bytes).unwrap();
+
+    assert_eq!(8, font.size);
+    assert_eq!(vec![(6, 3), (7, 3)], alive(font.glyph('\u{3}').unwrap()));
+    assert_eq!(16, font.advance('A', None, 16));
+}
+
+#[test]
+fn test_from_bytes_errors() {
+    assert!(BitmapFont::from_bytes(b"hello").is_err());
+    assert!(BitmapFont::from_bytes(&[0x36, 0x04, 0, 8, 0xff]).is_err());
+    assert!(BitmapFont::from_bytes(b"STARTFONT 2.1\nENDFONT\n").is_err());
+    assert!(BitmapFont::from_bytes(BDF.replace("BBX 1 5 1 0", "BBX 1 5 1 4000000000").as_bytes()).is_err());
+    assert!(BitmapFont::from_bytes(BDF.replace("40\n80", "4\u{e9}\n80").as_bytes()).is_err());
+    assert!(BitmapFont::from_bytes(BDF.replace("FONTBOUNDINGBOX 4 6 0 -1", "FONTBOUNDINGBOX 0 0 0 0").as_bytes()).is_err());
+    assert!(BitmapFont::from_bytes(BDF.replace("FONTBOUNDINGBOX 4 6 0 -1", "FONTBOUNDINGBOX 4 0 0 -1").as_bytes()).is_err());
+    assert!(BitmapFont::from_bytes(BDF.replace("DWIDTH 4 0", "DWIDTH 9223372036854775807 0").as_bytes()).is_err());
+    assert!(BitmapFont::from_bytes(BDF.replace("DWIDTH 4 0", "DWIDTH -4 0").as_bytes()).is_err());
+
+    // PSF2 headers whose glyph count and size overflow, or whose glyphs are
+    // far too large to allocate.
+    let header = |count: u32, glyph_size: u32, height: u32, width: u32| {
+        let mut bytes = PSF2_MAGIC.to_vec();
+        for &word in &[0, 32, 0, count, glyph_size, height, width] {
+            bytes.extend(&[word as u8, (word >> 8) as u8, (word >> 16) as u8, (word >> 24) as u8]);
+        }
+        bytes
+    };
+    assert!(BitmapFont::from_bytes(&header(0x8000_0000, 0x8000_0000, 8, 8)).is_err());
+    assert!(BitmapFont::from_bytes(&header(0xffff_ffff, 0, 8, 8)).is_err());
+    assert!(BitmapFont::from_bytes(&header(1, 8, 0xffff_ffff, 0xffff_ffff)).is_err());
+}
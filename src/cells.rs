@@ -1,70 +1,104 @@
+use std::str::FromStr;
 use fonts::*;
 use primes::*;
 
-#[repr(u8)]
+pub const WORD_BITS: usize = 64;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub enum Cell {
-    Dead = 0,
-    Alive = 1,
+pub enum Topology {
+    Torus,
+    Dead,
+    Horizontal,
+    Vertical,
+    KleinBottle,
 }
 
+impl Topology {
+    pub fn wraps_x(&self) -> bool {
+        match *self {
+            Topology::Torus | Topology::Horizontal | Topology::KleinBottle => true,
+            Topology::Dead | Topology::Vertical => false,
+        }
+    }
+
+    pub fn wraps_y(&self) -> bool {
+        match *self {
+            Topology::Torus | Topology::Vertical | Topology::KleinBottle => true,
+            Topology::Dead | Topology::Horizontal => false,
+        }
+    }
+}
+
+impl FromStr for Topology {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Topology, String> {
+        match s.to_lowercase().as_str() {
+            "torus" => Ok(Topology::Torus),
+            "dead" => Ok(Topology::Dead),
+            "horizontal" => Ok(Topology::Horizontal),
+            "vertical" => Ok(Topology::Vertical),
+            "klein" => Ok(Topology::KleinBottle),
+            _ => Err(format!("unknown topology: {}", s)),
+        }
+    }
+}
+
+// Rows are stored as bit-packed words, one bit per cell, with unused bits at
+// the end of each row kept dead.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Cells {
     pub size_x: usize,
     pub size_y: usize,
-    pub cells: Vec<Vec<Cell>>,
+    pub words: usize,
+    pub rows: Vec<u64>,
+    pub topology: Topology,
 }
 
 impl Cells {
     pub fn new(size_x: usize, size_y: usize) -> Cells {
+        let words = size_x.div_ceil(WORD_BITS);
         Cells {
             size_x: size_x,
             size_y: size_y,
-            cells: vec![vec![Cell::Dead; size_y]; size_x],
+            words: words,
+            rows: vec![0; words * size_y],
+            topology: Topology::Torus,
         }
     }
 
     pub fn from_vec(cells: Vec<Vec<u8>>) -> Self {
-        let size_x = cells.len();
-        let size_y = cells.iter()
+        let size_x = cells.iter()
             .map(|v| v.len())
             .max()
             .unwrap();
-        let mut normalized = vec![vec![Cell::Dead; size_y]; size_x];
+        let mut normalized = Cells::new(size_x, cells.len());
 
-        for x in 0..cells.len() {
-            for y in 0..cells[x].len() {
-                if cells[x][y] > 0 {
-                    normalized[y][x] = Cell::Alive;
+        for (y, row) in cells.iter().enumerate() {
+            for (x, &cell) in row.iter().enumerate() {
+                if cell > 0 {
+                    normalized.make_alive(x, y);
                 }
             }
         }
 
-        Cells {
-            size_x: size_x,
-            size_y: size_y,
-            cells: normalized,
-        }
+        normalized
     }
 
     pub fn from_font(font: Font) -> Self {
-        let size_x = font.val.len();
-        let size_y = font.val[0].len();
-        let mut normalized = vec![vec![Cell::Dead; size_y]; size_x];
-
-        for x in 0..size_x {
-            for y in 0..size_y {
-                if font.val[x][y] > 0 {
-                    normalized[y][x] = Cell::Alive;
+        let size_x = font.val[0].len();
+        let size_y = font.val.len();
+        let mut normalized = Cells::new(size_x, size_y);
+
+        for y in 0..size_y {
+            for x in 0..size_x {
+                if font.val[y][x] > 0 {
+                    normalized.make_alive(x, y);
                 }
             }
         }
 
-        Cells {
-            size_x: size_x,
-            size_y: size_y,
-            cells: normalized,
-        }
+        normalized
     }
 
     pub fn cell_x(&self, x: i64) -> usize {
@@ -77,10 +111,50 @@ impl Cells {
         (((y % sy) + sy) % sy) as usize
     }
 
+    // Maps a coordinate that may lie outside the board onto the board according
+    // to the topology, or returns None if it falls off a dead edge. Crossing the
+    // top or bottom edge of a Klein bottle mirrors the board horizontally.
+    pub fn locate(&self, x: i64, y: i64) -> Option<(usize, usize)> {
+        let sx = self.size_x as i64;
+        let sy = self.size_y as i64;
+        let mut x = x;
+
+        if y < 0 || y >= sy {
+            if !self.topology.wraps_y() {
+                return None;
+            }
+            let crossings = if y < 0 { (y + 1) / sy - 1 } else { y / sy };
+            if self.topology == Topology::KleinBottle && crossings % 2 != 0 {
+                x = sx - 1 - x;
+            }
+        }
+        if (x < 0 || x >= sx) && !self.topology.wraps_x() {
+            return None;
+        }
+
+        Some((self.cell_x(x), self.cell_y(y)))
+    }
+
+    pub fn row(&self, y: usize) -> &[u64] {
+        &self.rows[y * self.words..(y + 1) * self.words]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [u64] {
+        &mut self.rows[y * self.words..(y + 1) * self.words]
+    }
+
+    // Mask of the bits in the last word of a row that belong to the board.
+    pub fn last_word_mask(&self) -> u64 {
+        match self.size_x % WORD_BITS {
+            0 => !0,
+            n => (1 << n) - 1,
+        }
+    }
+
     pub fn make_alive(&mut self, x: usize, y: usize) {
-        let cx = self.cell_x(x as i64);
-        let cy = self.cell_y(y as i64);
-        self.cells[cx][cy] = Cell::Alive;
+        if let Some((cx, cy)) = self.locate(x as i64, y as i64) {
+            self.rows[cy * self.words + cx / WORD_BITS] |= 1 << (cx % WORD_BITS);
+        }
     }
 
     pub fn make_alive_square(&mut self, x: usize, y: usize, size: usize) {
@@ -92,23 +166,99 @@ impl Cells {
     }
 
     pub fn make_dead(&mut self, x: usize, y: usize) {
-        let cx = self.cell_x(x as i64);
-        let cy = self.cell_y(y as i64);
-        self.cells[cx][cy] = Cell::Dead;
+        if let Some((cx, cy)) = self.locate(x as i64, y as i64) {
+            self.rows[cy * self.words + cx / WORD_BITS] &= !(1 << (cx % WORD_BITS));
+        }
     }
 
     pub fn is_alive(&self, x: usize, y: usize) -> bool {
-        let cx = self.cell_x(x as i64);
-        let cy = self.cell_y(y as i64);
-        self.cells[cx][cy] == Cell::Alive
+        match self.locate(x as i64, y as i64) {
+            Some((cx, cy)) => (self.rows[cy * self.words + cx / WORD_BITS] >> (cx % WORD_BITS)) & 1 == 1,
+            None => false,
+        }
+    }
+
+    pub fn count_alive(&self) -> u64 {
+        self.rows.iter().map(|word| word.count_ones() as u64).sum()
     }
 
     pub fn clear(&mut self) {
+        for word in self.rows.iter_mut() {
+            *word = 0;
+        }
+    }
+
+    pub fn columns(&self) -> Option<(usize, usize)> {
+        let alive: Vec<usize> = (0..self.size_x)
+            .filter(|&x| (0..self.size_y).any(|y| self.is_alive(x, y)))
+            .collect();
+
+        match (alive.first(), alive.last()) {
+            (Some(&left), Some(&right)) => Some((left, right)),
+            _ => None,
+        }
+    }
+
+    pub fn crop(&self, x: usize, y: usize, size_x: usize, size_y: usize) -> Cells {
+        let mut cropped = Cells::new(size_x, size_y);
+        for x2 in 0..size_x {
+            for y2 in 0..size_y {
+                if self.is_alive(x + x2, y + y2) {
+                    cropped.make_alive(x2, y2);
+                }
+            }
+        }
+        cropped
+    }
+
+    // Like `is_alive`, but cells beyond the edges are dead whatever the
+    // topology.
+    fn is_alive_inside(&self, x: i64, y: i64) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.size_x && (y as usize) < self.size_y
+            && self.is_alive(x as usize, y as usize)
+    }
+
+    // Dilates the alive cells with a 2x2 square, so every stroke grows by one
+    // cell to the right and down.
+    pub fn dilate(&self) -> Cells {
+        let mut dilated = Cells::new(self.size_x, self.size_y);
+        for x in 0..self.size_x as i64 {
+            for y in 0..self.size_y as i64 {
+                if self.is_alive_inside(x, y) || self.is_alive_inside(x - 1, y)
+                    || self.is_alive_inside(x, y - 1) || self.is_alive_inside(x - 1, y - 1) {
+                    dilated.make_alive(x as usize, y as usize);
+                }
+            }
+        }
+        dilated
+    }
+
+    // Erodes the alive cells with a cross, keeping those whose four
+    // neighbours are all alive.
+    pub fn erode(&self) -> Cells {
+        let mut eroded = Cells::new(self.size_x, self.size_y);
+        for x in 0..self.size_x as i64 {
+            for y in 0..self.size_y as i64 {
+                if self.is_alive_inside(x, y) && self.is_alive_inside(x - 1, y) && self.is_alive_inside(x + 1, y)
+                    && self.is_alive_inside(x, y - 1) && self.is_alive_inside(x, y + 1) {
+                    eroded.make_alive(x as usize, y as usize);
+                }
+            }
+        }
+        eroded
+    }
+
+    // The alive cells that are dead in `other`.
+    pub fn difference(&self, other: &Cells) -> Cells {
+        let mut difference = Cells::new(self.size_x, self.size_y);
         for x in 0..self.size_x {
             for y in 0..self.size_y {
-                self.make_dead(x, y);
+                if self.is_alive(x, y) && !other.is_alive(x, y) {
+                    difference.make_alive(x, y);
+                }
             }
         }
+        difference
     }
 
     pub fn is_allocatable(&self, x: usize, y: usize, size: usize) -> bool {
@@ -124,12 +274,13 @@ impl Cells {
     }
 
     pub fn allocate(&mut self, cells: Cells, x: usize, y: usize, scale: f64) {
-        let scaled_x = cells.size_x * scale as usize;
-        let scaled_y = cells.size_y * scale as usize;
+        let scaled_x = (cells.size_x as f64 * scale).round() as usize;
+        let scaled_y = (cells.size_y as f64 * scale).round() as usize;
         for x2 in 0..scaled_x {
             for y2 in 0..scaled_y {
-                let fx = (x2 as f64 / scale) as usize;
-                let fy = (y2 as f64 / scale) as usize;
+                // Sample the cell under the centre, so fractional scales work.
+                let fx = (((x2 as f64 + 0.5) / scale) as usize).min(cells.size_x - 1);
+                let fy = (((y2 as f64 + 0.5) / scale) as usize).min(cells.size_y - 1);
                 if cells.is_alive(fx, fy) {
                     self.make_alive(x + x2, y + y2);
                 } else {
@@ -139,10 +290,15 @@ impl Cells {
         }
     }
 
-    pub fn allocate_prime(&mut self, prime: Prime, x: usize, y: usize) {
+    pub fn allocate_prime(&mut self, prime: &Prime, x: usize, y: usize) {
+        self.allocate_phase(prime, 0, x, y);
+    }
+
+    pub fn allocate_phase(&mut self, prime: &Prime, phase: usize, x: usize, y: usize) {
+        let mask = prime.phase(phase);
         for x2 in 0..prime.exclusive_size {
             for y2 in 0..prime.exclusive_size {
-                if prime.prime[x2][y2] > 0 {
+                if mask[x2][y2] > 0 {
                     self.make_alive(x + x2, y + y2);
                 } else {
                     self.make_dead(x + x2, y + y2);
@@ -158,7 +314,12 @@ fn test_new() {
 
     assert_eq!(2, cells.size_x);
     assert_eq!(2, cells.size_y);
-    assert_eq!(vec![vec![Cell::Dead; 2]; 2], cells.cells);
+    assert_eq!(vec![0, 0], cells.rows);
+
+    let cells = Cells::new(130, 3);
+
+    assert_eq!(3, cells.words);
+    assert_eq!(vec![0; 9], cells.rows);
 }
 
 #[test]
@@ -172,13 +333,41 @@ fn test_from_vec() {
 
     assert_eq!(3, cells.size_x);
     assert_eq!(3, cells.size_y);
-    assert_eq!(
+    assert_eq!(vec![0b111, 0b001, 0b000], cells.rows);
+
+    let cells = Cells::from_vec(
         vec![
-            vec![Cell::Alive, Cell::Alive, Cell::Dead],
-            vec![Cell::Alive, Cell::Dead, Cell::Dead],
-            vec![Cell::Alive, Cell::Dead, Cell::Dead]
-        ],
-         cells.cells);
+            vec![1, 0, 0, 1],
+            vec![0, 1, 0, 0],
+        ]);
+
+    assert_eq!(4, cells.size_x);
+    assert_eq!(2, cells.size_y);
+    assert!(cells.is_alive(3, 0));
+    assert!(cells.is_alive(1, 1));
+    assert!(!cells.is_alive(0, 1));
+}
+
+#[test]
+fn test_last_word_mask() {
+    assert_eq!(0b111, Cells::new(3, 1).last_word_mask());
+    assert_eq!(!0, Cells::new(128, 1).last_word_mask());
+    assert_eq!(0b1, Cells::new(65, 1).last_word_mask());
+}
+
+#[test]
+fn test_wide_rows() {
+    let mut cells = Cells::new(100, 2);
+    cells.make_alive(70, 1);
+    cells.make_alive(99, 0);
+    cells.make_alive(100, 0);
+
+    assert_eq!(vec![1, 1 << 35, 0, 1 << 6], cells.rows);
+    assert!(cells.is_alive(70, 1));
+    assert!(cells.is_alive(0, 0));
+
+    cells.make_dead(70, 1);
+    assert_eq!(&[0, 0], cells.row(1));
 }
 
 #[test]
@@ -201,6 +390,42 @@ fn test_cell_y() {
     assert_eq!(3, cells.cell_y(-7));
 }
 
+#[test]
+fn test_locate() {
+    let mut cells = Cells::new(10, 5);
+
+    assert_eq!(Some((9, 4)), cells.locate(-1, -1));
+    assert_eq!(Some((3, 2)), cells.locate(3, 2));
+
+    cells.topology = Topology::Dead;
+    assert_eq!(None, cells.locate(-1, 2));
+    assert_eq!(None, cells.locate(3, 5));
+    assert_eq!(Some((3, 2)), cells.locate(3, 2));
+
+    cells.topology = Topology::Horizontal;
+    assert_eq!(Some((9, 2)), cells.locate(-1, 2));
+    assert_eq!(None, cells.locate(3, -1));
+
+    cells.topology = Topology::Vertical;
+    assert_eq!(None, cells.locate(-1, 2));
+    assert_eq!(Some((3, 4)), cells.locate(3, -1));
+
+    cells.topology = Topology::KleinBottle;
+    assert_eq!(Some((9, 2)), cells.locate(-1, 2));
+    assert_eq!(Some((6, 4)), cells.locate(3, -1));
+    assert_eq!(Some((6, 0)), cells.locate(3, 5));
+    assert_eq!(Some((3, 0)), cells.locate(3, 10));
+    assert_eq!(Some((0, 0)), cells.locate(-1, 5));
+}
+
+#[test]
+fn test_topology_from_str() {
+    assert_eq!(Ok(Topology::Torus), "torus".parse());
+    assert_eq!(Ok(Topology::Dead), "Dead".parse());
+    assert_eq!(Ok(Topology::KleinBottle), "klein".parse());
+    assert!("sphere".parse::<Topology>().is_err());
+}
+
 #[test]
 fn test_make_alive() {
     let mut cells = Cells::new(2, 2);
@@ -249,6 +474,17 @@ fn test_is_alive() {
     assert!(!cells.is_alive(1, 1));
 }
 
+#[test]
+fn test_count_alive() {
+    let mut cells = Cells::new(130, 3);
+    cells.make_alive(0, 0);
+    cells.make_alive(64, 1);
+    cells.make_alive(129, 2);
+
+    assert_eq!(3, cells.count_alive());
+    assert_eq!(0, Cells::new(5, 5).count_alive());
+}
+
 #[test]
 fn test_clear() {
     let mut cells = Cells::from_vec(vec![vec![1, 1], vec![1, 1]]);
@@ -259,6 +495,39 @@ fn test_clear() {
     assert_eq!(expected, cells);
 }
 
+#[test]
+fn test_columns() {
+    let cells = Cells::from_vec(vec![
+        vec![0, 0, 0, 0, 0],
+        vec![0, 0, 1, 0, 0],
+        vec![0, 1, 0, 0, 0],
+        vec![0, 0, 0, 1, 0],
+        vec![0, 0, 0, 0, 0],
+    ]);
+
+    assert_eq!(Some((1, 3)), cells.columns());
+    assert_eq!(None, Cells::new(5, 5).columns());
+}
+
+#[test]
+fn test_crop() {
+    let cells = Cells::from_vec(vec![
+        vec![0, 0, 0, 0, 0],
+        vec![0, 0, 1, 0, 0],
+        vec![0, 1, 0, 0, 0],
+        vec![0, 0, 0, 1, 0],
+        vec![0, 0, 0, 0, 0],
+    ]);
+
+    let expected = Cells::from_vec(vec![
+        vec![0, 1, 0],
+        vec![1, 0, 0],
+        vec![0, 0, 1],
+    ]);
+
+    assert_eq!(expected, cells.crop(1, 1, 3, 3));
+}
+
 #[test]
 fn test_is_allocatable() {
     let cells = Cells::from_vec(vec![
@@ -278,6 +547,31 @@ fn test_is_allocatable() {
     assert!(!cells.is_allocatable(3, 3, 2));
 }
 
+#[test]
+fn test_morphology() {
+    let cells = Cells::from_vec(vec![
+        vec![0, 0, 0, 0, 0],
+        vec![0, 1, 1, 1, 0],
+        vec![0, 1, 1, 1, 0],
+        vec![0, 1, 1, 1, 0],
+        vec![0, 0, 0, 0, 0],
+    ]);
+
+    let dilated = cells.dilate();
+    assert_eq!(16, dilated.count_alive());
+    assert!(dilated.is_alive(4, 4) && !dilated.is_alive(0, 1));
+
+    let eroded = cells.erode();
+    assert_eq!(1, eroded.count_alive());
+    assert!(eroded.is_alive(2, 2));
+    // Cells beyond the edges are dead.
+    assert_eq!(0, Cells::from_vec(vec![vec![1, 1], vec![1, 1]]).erode().count_alive());
+
+    let outline = cells.difference(&eroded);
+    assert_eq!(8, outline.count_alive());
+    assert!(!outline.is_alive(2, 2) && outline.is_alive(1, 1));
+}
+
 #[test]
 fn test_allocate() {
     let mut cells = Cells::new(10, 10);
@@ -298,13 +592,17 @@ fn test_allocate() {
     ]);
 
     assert_eq!(expected, cells);
+
+    let mut cells = Cells::new(3, 1);
+    cells.allocate(Cells::from_vec(vec![vec![1, 0]]), 0, 0, 1.5);
+    assert_eq!(Cells::from_vec(vec![vec![1, 0, 0]]), cells);
 }
 
 #[test]
 fn test_allocate_prime() {
     let mut cells = Cells::new(10, 10);
 
-    cells.allocate_prime(BEACON, 2, 2);
+    cells.allocate_prime(&BEACON, 2, 2);
     let expected = Cells::from_vec(vec![
         vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
         vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
@@ -320,3 +618,25 @@ fn test_allocate_prime() {
 
     assert_eq!(expected, cells);
 }
+
+#[test]
+fn test_allocate_phase() {
+    let mut cells = Cells::new(7, 7);
+    cells.allocate_phase(&BLINKER, 1, 1, 1);
+    let mut expected = Cells::new(7, 7);
+    expected.make_alive(2, 3);
+    expected.make_alive(3, 3);
+    expected.make_alive(4, 3);
+
+    assert_eq!(expected, cells);
+    cells.allocate_phase(&BLINKER, 2, 1, 1);
+    assert_eq!(Cells::from_vec(vec![
+        vec![0, 0, 0, 0, 0, 0, 0],
+        vec![0, 0, 0, 0, 0, 0, 0],
+        vec![0, 0, 0, 1, 0, 0, 0],
+        vec![0, 0, 0, 1, 0, 0, 0],
+        vec![0, 0, 0, 1, 0, 0, 0],
+        vec![0, 0, 0, 0, 0, 0, 0],
+        vec![0, 0, 0, 0, 0, 0, 0],
+    ]), cells);
+}
@@ -1,6 +1,11 @@
 use cells::*;
+#[cfg(test)]
+use evolve::*;
 use primes::*;
+#[cfg(test)]
+use rule::*;
 
+#[derive(Clone, Debug, PartialEq)]
 pub struct Compound {
     pub font_size: usize,
     pub pattern: Vec<Vec<Prime<'static>>>,
@@ -22,16 +27,99 @@ impl Compound {
         self.pattern[x][y] = EMPTY;
     }
 
-    pub fn to_cells(&self) -> Cells {
-        let mut cells = Cells::new(self.font_size, self.font_size);
+    pub fn placements(&self) -> Vec<(usize, usize, Prime<'static>)> {
+        let mut placements = Vec::new();
         for x in 0..self.font_size {
             for y in 0..self.font_size {
                 let p = &self.pattern[x][y];
                 if p.prime_type != PrimeType::Empty {
-                    cells.allocate_prime(*p, x, y);
+                    placements.push((x, y, p.clone()));
                 }
             }
         }
+        placements
+    }
+
+    pub fn fits(&self, prime: &Prime, x: usize, y: usize) -> bool {
+        let size = prime.exclusive_size;
+        if x + size > self.font_size || y + size > self.font_size {
+            return false;
+        }
+
+        self.placements().iter().all(|&(x2, y2, ref p)| {
+            x + size <= x2 || x2 + p.exclusive_size <= x || y + size <= y2 || y2 + p.exclusive_size <= y
+        })
+    }
+
+    // The number of generations after which every prime is back in its first phase.
+    pub fn period(&self) -> usize {
+        self.placements().iter().fold(1, |acc, (_, _, p)| lcm(acc, p.period))
+    }
+
+    pub fn to_cells(&self) -> Cells {
+        self.to_cells_at(0)
+    }
+
+    // Renders the compound as it looks `phase` generations after it was placed.
+    pub fn to_cells_at(&self, phase: usize) -> Cells {
+        let mut cells = Cells::new(self.font_size, self.font_size);
+        for (x, y, p) in self.placements() {
+            cells.allocate_phase(&p, phase, x, y);
+        }
         cells
     }
 }
+
+pub fn lcm(a: usize, b: usize) -> usize {
+    let (mut x, mut y) = (a, b);
+    while y != 0 {
+        let t = x % y;
+        x = y;
+        y = t;
+    }
+    a / x * b
+}
+
+#[test]
+fn test_fits() {
+    let mut compound = Compound::new(12);
+    compound.set(BLOCK, 4, 4);
+
+    assert!(compound.fits(&BLOCK, 0, 0));
+    assert!(compound.fits(&BLOCK, 0, 8));
+    assert!(!compound.fits(&TUB, 0, 7));
+    assert!(compound.fits(&BLOCK, 8, 8));
+    assert!(!compound.fits(&BLOCK, 1, 1));
+    assert!(!compound.fits(&BEACON, 5, 0));
+    assert!(!compound.fits(&BLOCK, 9, 0));
+}
+
+#[test]
+fn test_period() {
+    let mut compound = Compound::new(20);
+
+    assert_eq!(1, compound.period());
+    compound.set(BLOCK, 0, 0);
+    assert_eq!(1, compound.period());
+    compound.set(BLINKER, 5, 5);
+    compound.set(TOAD, 10, 10);
+    assert_eq!(2, compound.period());
+}
+
+#[test]
+fn test_to_cells_at() {
+    let mut compound = Compound::new(20);
+    compound.set(BLOCK, 0, 0);
+    compound.set(BLINKER, 6, 0);
+    compound.set(TOAD, 0, 8);
+    compound.set(CLOCK, 10, 10);
+
+    let mut cells = compound.to_cells();
+    cells.topology = Topology::Dead;
+    for phase in 0..4 {
+        let mut expected = compound.to_cells_at(phase);
+        expected.topology = Topology::Dead;
+        assert_eq!(expected, cells, "phase {}", phase);
+        cells = next(&cells, &Rule::conway());
+    }
+}
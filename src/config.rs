@@ -1,6 +1,46 @@
+use std::collections::HashMap;
+use cells::{Cells, Topology};
+use fitness::{FitnessKind, PhaseScoring};
+use layout::Align;
+use mold::{BuiltIn, TextStyle, Typeface};
+use optimizer::OptimizerKind;
+use primes::Prime;
+use resample::Resampling;
+use rule::Rule;
+use svg::Svg;
+
 pub struct Config {
     pub cell_size: usize,
     pub font_size: usize,
+    pub margin: usize,
+    pub letter_spacing: usize,
+    pub line_spacing: usize,
+    pub align: Align,
+    pub word_wrap: bool,
+    pub rule: Rule,
+    pub topology: Topology,
+    pub seed: Option<u64>,
+    pub optimizer: OptimizerKind,
+    // How many times gradient descent tries to improve each glyph.
+    pub gradient_passes: usize,
+    // How many moves simulated annealing proposes for each glyph.
+    pub annealing_steps: usize,
+    // How many nodes the exact optimizer visits before settling for the best
+    // tiling found so far.
+    pub node_limit: usize,
+    pub fitness: FitnessKind,
+    pub phase_scoring: PhaseScoring,
+    pub population_size: usize,
+    pub generations: usize,
+    pub primes: Vec<Prime<'static>>,
+    pub font: Box<dyn Typeface>,
+    // Drawings used in place of the glyphs of some characters.
+    pub icons: HashMap<char, Svg>,
+    // Bitmaps used in place of the glyphs of some characters.
+    pub images: HashMap<char, Cells>,
+    pub text_style: TextStyle,
+    pub resampling: Resampling,
+    pub dither: bool,
     pub background_color: String,
     pub cell_color: String,
     pub grid_color: String,
@@ -11,6 +51,29 @@ impl Config {
         Config {
             cell_size: 4,
             font_size: 40,
+            margin: 5,
+            letter_spacing: 2,
+            line_spacing: 5,
+            align: Align::Left,
+            word_wrap: false,
+            rule: Rule::conway(),
+            topology: Topology::Torus,
+            seed: None,
+            optimizer: OptimizerKind::GradientDescent,
+            gradient_passes: 5,
+            annealing_steps: 2000,
+            node_limit: 5000000,
+            fitness: FitnessKind::Weighted,
+            phase_scoring: PhaseScoring::Average,
+            population_size: 30,
+            generations: 60,
+            primes: Vec::new(),
+            font: Box::new(BuiltIn),
+            icons: HashMap::new(),
+            images: HashMap::new(),
+            text_style: TextStyle::Normal,
+            resampling: Resampling::Nearest,
+            dither: false,
             background_color: "white".to_string(),
             cell_color: "black".to_string(),
             grid_color: "black".to_string(),
@@ -0,0 +1,458 @@
+use std::collections::HashMap;
+use std::mem;
+use cells::*;
+use evolve::*;
+use rule::*;
+
+pub trait Engine {
+    fn advance(&mut self, cells: &mut Cells, rule: &Rule, generations: u64) -> Result<(), String>;
+}
+
+pub struct Stepper {
+    buffer: Cells,
+}
+
+impl Stepper {
+    pub fn new() -> Stepper {
+        Stepper {
+            buffer: Cells::new(0, 0),
+        }
+    }
+}
+
+impl Engine for Stepper {
+    fn advance(&mut self, cells: &mut Cells, rule: &Rule, generations: u64) -> Result<(), String> {
+        if self.buffer.size_x != cells.size_x || self.buffer.size_y != cells.size_y {
+            self.buffer = Cells::new(cells.size_x, cells.size_y);
+        }
+        for _ in 0..generations {
+            next_into(cells, &mut self.buffer, rule);
+            mem::swap(cells, &mut self.buffer);
+        }
+        Ok(())
+    }
+}
+
+const DEAD: usize = 0;
+const ALIVE: usize = 1;
+// Joining more nodes than this fails, and the jump is retried in shorter steps
+// on a cleared table.
+const MAX_NODES: usize = 1 << 22;
+// How many generations a bounded board is stepped looking for a cycle before
+// a long jump gives up.
+const MAX_BOUNDED_GENERATIONS: u64 = 1 << 16;
+
+#[derive(Clone, Copy)]
+struct Node {
+    level: usize,
+    nw: usize,
+    ne: usize,
+    sw: usize,
+    se: usize,
+}
+
+// Memoized quadtree in the style of Gosper's Hashlife. A torus board is tiled
+// around a quadtree large enough that the light cone of the jump never reaches
+// past the tiled copies. Other topologies cannot be tiled, so they are stepped
+// until the board repeats itself and the rest of the jump is skipped.
+pub struct Hashlife {
+    stepper: Stepper,
+    rule: Option<Rule>,
+    nodes: Vec<Node>,
+    index: HashMap<[usize; 4], usize>,
+    empty: Vec<usize>,
+    results: HashMap<(usize, usize), usize>,
+    max_nodes: usize,
+}
+
+impl Hashlife {
+    pub fn new() -> Hashlife {
+        let mut hashlife = Hashlife {
+            stepper: Stepper::new(),
+            rule: None,
+            nodes: Vec::new(),
+            index: HashMap::new(),
+            empty: Vec::new(),
+            results: HashMap::new(),
+            max_nodes: MAX_NODES,
+        };
+        hashlife.reset();
+        hashlife
+    }
+
+    fn reset(&mut self) {
+        let leaf = Node { level: 0, nw: DEAD, ne: DEAD, sw: DEAD, se: DEAD };
+        self.nodes = vec![leaf, leaf];
+        self.index.clear();
+        self.empty = vec![DEAD];
+        self.results.clear();
+    }
+
+    // Returns None instead of a new node once the table is full.
+    fn join(&mut self, nw: usize, ne: usize, sw: usize, se: usize) -> Option<usize> {
+        let key = [nw, ne, sw, se];
+        if let Some(&id) = self.index.get(&key) {
+            return Some(id);
+        }
+        if self.nodes.len() >= self.max_nodes {
+            return None;
+        }
+        Some(self.insert(key))
+    }
+
+    fn insert(&mut self, key: [usize; 4]) -> usize {
+        let [nw, ne, sw, se] = key;
+        let level = self.nodes[nw].level + 1;
+        self.nodes.push(Node { level: level, nw: nw, ne: ne, sw: sw, se: se });
+        let id = self.nodes.len() - 1;
+        self.index.insert(key, id);
+        id
+    }
+
+    // Empty nodes are added even to a full table: there is one per level.
+    fn empty(&mut self, level: usize) -> usize {
+        while self.empty.len() <= level {
+            let e = *self.empty.last().unwrap();
+            let node = match self.index.get(&[e, e, e, e]) {
+                Some(&id) => id,
+                None => self.insert([e, e, e, e]),
+            };
+            self.empty.push(node);
+        }
+        self.empty[level]
+    }
+
+    fn is_alive(&self, id: usize, x: usize, y: usize) -> bool {
+        let node = self.nodes[id];
+        if node.level == 0 {
+            return id == ALIVE;
+        }
+        let half = 1 << (node.level - 1);
+        match (x < half, y < half) {
+            (true, true) => self.is_alive(node.nw, x, y),
+            (false, true) => self.is_alive(node.ne, x - half, y),
+            (true, false) => self.is_alive(node.sw, x, y - half),
+            (false, false) => self.is_alive(node.se, x - half, y - half),
+        }
+    }
+
+    // Advances the centre 2x2 of a 4x4 node by one generation.
+    fn life_4x4(&mut self, id: usize, rule: &Rule) -> Option<usize> {
+        let mut alive = [[false; 4]; 4];
+        for (x, column) in alive.iter_mut().enumerate() {
+            for (y, cell) in column.iter_mut().enumerate() {
+                *cell = self.is_alive(id, x, y);
+            }
+        }
+
+        let mut center = [DEAD; 4];
+        for (i, &(x, y)) in [(1, 1), (2, 1), (1, 2), (2, 2)].iter().enumerate() {
+            let count = alive[x - 1..x + 2].iter()
+                .flat_map(|column| &column[y - 1..y + 2])
+                .filter(|&&a| a)
+                .count() - alive[x][y] as usize;
+            if rule.next_state(alive[x][y], count) {
+                center[i] = ALIVE;
+            }
+        }
+        self.join(center[0], center[1], center[2], center[3])
+    }
+
+    // Returns the centre half of the node advanced by 2^j generations, or
+    // None if the table fills up on the way.
+    fn successor(&mut self, id: usize, j: usize, rule: &Rule) -> Option<usize> {
+        let node = self.nodes[id];
+        if !rule.birth[0] && id == self.empty(node.level) {
+            return Some(self.empty(node.level - 1));
+        }
+        let j = j.min(node.level - 2);
+        if let Some(&result) = self.results.get(&(id, j)) {
+            return Some(result);
+        }
+
+        let result = if node.level == 2 {
+            self.life_4x4(id, rule)?
+        } else {
+            let (a, b, c, d) = (self.nodes[node.nw], self.nodes[node.ne], self.nodes[node.sw], self.nodes[node.se]);
+            let n1 = self.join(a.nw, a.ne, a.sw, a.se)?;
+            let n2 = self.join(a.ne, b.nw, a.se, b.sw)?;
+            let n3 = self.join(b.nw, b.ne, b.sw, b.se)?;
+            let n4 = self.join(a.sw, a.se, c.nw, c.ne)?;
+            let n5 = self.join(a.se, b.sw, c.ne, d.nw)?;
+            let n6 = self.join(b.sw, b.se, d.nw, d.ne)?;
+            let n7 = self.join(c.nw, c.ne, c.sw, c.se)?;
+            let n8 = self.join(c.ne, d.nw, c.se, d.sw)?;
+            let n9 = self.join(d.nw, d.ne, d.sw, d.se)?;
+            let c1 = self.successor(n1, j, rule)?;
+            let c2 = self.successor(n2, j, rule)?;
+            let c3 = self.successor(n3, j, rule)?;
+            let c4 = self.successor(n4, j, rule)?;
+            let c5 = self.successor(n5, j, rule)?;
+            let c6 = self.successor(n6, j, rule)?;
+            let c7 = self.successor(n7, j, rule)?;
+            let c8 = self.successor(n8, j, rule)?;
+            let c9 = self.successor(n9, j, rule)?;
+
+            if j < node.level - 2 {
+                let (c1, c2, c3) = (self.nodes[c1], self.nodes[c2], self.nodes[c3]);
+                let (c4, c5, c6) = (self.nodes[c4], self.nodes[c5], self.nodes[c6]);
+                let (c7, c8, c9) = (self.nodes[c7], self.nodes[c8], self.nodes[c9]);
+                let nw = self.join(c1.se, c2.sw, c4.ne, c5.nw)?;
+                let ne = self.join(c2.se, c3.sw, c5.ne, c6.nw)?;
+                let sw = self.join(c4.se, c5.sw, c7.ne, c8.nw)?;
+                let se = self.join(c5.se, c6.sw, c8.ne, c9.nw)?;
+                self.join(nw, ne, sw, se)?
+            } else {
+                let q1 = self.join(c1, c2, c4, c5)?;
+                let q2 = self.join(c2, c3, c5, c6)?;
+                let q3 = self.join(c4, c5, c7, c8)?;
+                let q4 = self.join(c5, c6, c8, c9)?;
+                let nw = self.successor(q1, j, rule)?;
+                let ne = self.successor(q2, j, rule)?;
+                let sw = self.successor(q3, j, rule)?;
+                let se = self.successor(q4, j, rule)?;
+                self.join(nw, ne, sw, se)?
+            }
+        };
+
+        self.results.insert((id, j), result);
+        Some(result)
+    }
+
+    // Builds the quadtree of the given level whose top-left corner is the
+    // torus cell (x, y). The board repeats itself, so subtrees are memoized
+    // by their position on the torus.
+    fn build(&mut self, cells: &Cells, level: usize, x: i64, y: i64, built: &mut HashMap<(usize, usize, usize), usize>) -> Option<usize> {
+        let cx = cells.cell_x(x);
+        let cy = cells.cell_y(y);
+        if level == 0 {
+            return Some(if cells.is_alive(cx, cy) { ALIVE } else { DEAD });
+        }
+        if let Some(&id) = built.get(&(level, cx, cy)) {
+            return Some(id);
+        }
+        let half = 1 << (level - 1);
+        let nw = self.build(cells, level - 1, x, y, built)?;
+        let ne = self.build(cells, level - 1, x + half, y, built)?;
+        let sw = self.build(cells, level - 1, x, y + half, built)?;
+        let se = self.build(cells, level - 1, x + half, y + half, built)?;
+        let id = self.join(nw, ne, sw, se)?;
+        built.insert((level, cx, cy), id);
+        Some(id)
+    }
+
+    fn write(&mut self, id: usize, x: usize, y: usize, cells: &mut Cells) {
+        if x >= cells.size_x || y >= cells.size_y {
+            return;
+        }
+        let node = self.nodes[id];
+        if node.level == 0 {
+            if id == ALIVE {
+                cells.make_alive(x, y);
+            }
+            return;
+        }
+        if id == self.empty(node.level) {
+            return;
+        }
+        let half = 1 << (node.level - 1);
+        self.write(node.nw, x, y, cells);
+        self.write(node.ne, x + half, y, cells);
+        self.write(node.sw, x, y + half, cells);
+        self.write(node.se, x + half, y + half, cells);
+    }
+
+    // Advances a torus board by 2^j generations. The root must cover the
+    // board with its centre half and be large enough to jump that far.
+    fn jump(&mut self, cells: &Cells, rule: &Rule, size: u64, j: usize) -> Option<usize> {
+        let mut level = 2;
+        while 1 << (level - 1) < size || 1 << (level - 1) <= 1u64 << j {
+            level += 1;
+        }
+        let offset = 1 << (level - 2);
+        let root = self.build(cells, level, -offset, -offset, &mut HashMap::new())?;
+        self.successor(root, j, rule)
+    }
+
+    // A board that does not wrap has finitely many states, so it ends up in a
+    // cycle. Brent's algorithm finds its period with a single saved board,
+    // after which only the remainder of the jump modulo the period is stepped.
+    fn advance_bounded(&mut self, cells: &mut Cells, rule: &Rule, generations: u64) -> Result<(), String> {
+        let original = cells.clone();
+        let mut checkpoint = cells.clone();
+        let (mut power, mut period) = (1, 0);
+        let mut stepped = 0;
+        while stepped < generations {
+            if stepped == MAX_BOUNDED_GENERATIONS {
+                *cells = original;
+                return Err(format!("the board does not repeat within {} generations", MAX_BOUNDED_GENERATIONS));
+            }
+            self.stepper.advance(cells, rule, 1)?;
+            stepped += 1;
+            period += 1;
+            if *cells == checkpoint {
+                return self.stepper.advance(cells, rule, (generations - stepped) % period);
+            }
+            if period == power {
+                checkpoint = cells.clone();
+                power *= 2;
+                period = 0;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Engine for Hashlife {
+    fn advance(&mut self, cells: &mut Cells, rule: &Rule, generations: u64) -> Result<(), String> {
+        if cells.topology != Topology::Torus {
+            return self.advance_bounded(cells, rule, generations);
+        }
+        if self.rule != Some(*rule) {
+            self.reset();
+            self.rule = Some(*rule);
+        }
+
+        let size = cells.size_x.max(cells.size_y) as u64;
+        let mut remaining = generations;
+        let mut j = 63;
+        while remaining > 0 {
+            while 1 << j > remaining {
+                j -= 1;
+            }
+            match self.jump(cells, rule, size, j) {
+                Some(result) => {
+                    cells.clear();
+                    self.write(result, 0, 0, cells);
+                    remaining -= 1 << j;
+                },
+                // The table filled up: start over with a shorter jump, and
+                // step a single generation if even that is too much.
+                None => {
+                    self.reset();
+                    if j == 0 {
+                        self.stepper.advance(cells, rule, 1)?;
+                        remaining -= 1;
+                    } else {
+                        j -= 1;
+                    }
+                },
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+fn test_board(size_x: usize, size_y: usize) -> Cells {
+    let mut cells = Cells::new(size_x, size_y);
+    for x in 0..size_x {
+        for y in 0..size_y {
+            if (x * 7 + y * 13 + x * y) % 5 < 2 {
+                cells.make_alive(x, y);
+            }
+        }
+    }
+    cells
+}
+
+#[test]
+fn test_stepper() {
+    let cells = test_board(20, 12);
+    let mut expected = cells.clone();
+    for _ in 0..3 {
+        expected = next(&expected, &Rule::conway());
+    }
+
+    let mut actual = cells.clone();
+    Stepper::new().advance(&mut actual, &Rule::conway(), 3).unwrap();
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_hashlife_matches_stepper() {
+    let rules: Vec<Rule> = vec![Rule::conway(), "B36/S23".parse().unwrap()];
+    let mut hashlife = Hashlife::new();
+
+    for rule in &rules {
+        for &(size_x, size_y) in &[(8, 8), (20, 12), (37, 70)] {
+            for &generations in &[1, 2, 7, 64, 100] {
+                let cells = test_board(size_x, size_y);
+                let mut expected = cells.clone();
+                Stepper::new().advance(&mut expected, rule, generations).unwrap();
+
+                let mut actual = cells.clone();
+                hashlife.advance(&mut actual, rule, generations).unwrap();
+
+                assert_eq!(expected, actual, "{} {}x{} {}", rule, size_x, size_y, generations);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_hashlife_with_dead_edges() {
+    let mut cells = test_board(20, 12);
+    cells.topology = Topology::Dead;
+    let mut expected = cells.clone();
+    Stepper::new().advance(&mut expected, &Rule::conway(), 50).unwrap();
+
+    Hashlife::new().advance(&mut cells, &Rule::conway(), 50).unwrap();
+
+    assert_eq!(expected, cells);
+}
+
+#[test]
+fn test_hashlife_glider() {
+    let mut cells = Cells::from_vec(vec![
+        vec![0, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+        vec![0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+        vec![1, 1, 1, 0, 0, 0, 0, 0, 0, 0],
+        vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    ]);
+    let expected = cells.clone();
+
+    // A glider travels one cell diagonally every four generations, so it is
+    // back where it started after crossing the board 30 times horizontally.
+    Hashlife::new().advance(&mut cells, &Rule::conway(), 4 * 30).unwrap();
+
+    assert_eq!(expected, cells);
+}
+
+#[test]
+fn test_hashlife_skips_cycles_on_bounded_boards() {
+    let mut cells = test_board(20, 12);
+    cells.topology = Topology::Dead;
+    let generations = 4_000_000_000;
+
+    // After 2000 generations only still lifes and oscillators with periods
+    // dividing 12 are left.
+    let mut expected = cells.clone();
+    Stepper::new().advance(&mut expected, &Rule::conway(), 2000).unwrap();
+    let mut cycled = expected.clone();
+    Stepper::new().advance(&mut cycled, &Rule::conway(), 12).unwrap();
+    assert_eq!(expected, cycled);
+    Stepper::new().advance(&mut expected, &Rule::conway(), (generations - 2000) % 12).unwrap();
+
+    Hashlife::new().advance(&mut cells, &Rule::conway(), generations).unwrap();
+
+    assert_eq!(expected, cells);
+}
+
+#[test]
+fn test_hashlife_with_full_table() {
+    let cells = test_board(37, 70);
+    let mut expected = cells.clone();
+    Stepper::new().advance(&mut expected, &Rule::conway(), 100).unwrap();
+
+    let mut hashlife = Hashlife::new();
+    hashlife.max_nodes = 500;
+    let mut actual = cells.clone();
+    hashlife.advance(&mut actual, &Rule::conway(), 100).unwrap();
+
+    assert_eq!(expected, actual);
+    assert!(hashlife.nodes.len() <= 500 + 64);
+}
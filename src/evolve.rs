@@ -1,21 +1,145 @@
 use cells::*;
+use rule::*;
 
-pub fn next(cells: &Cells) -> Cells {
+pub fn next(cells: &Cells, rule: &Rule) -> Cells {
     let mut new_cells = Cells::new(cells.size_x, cells.size_y);
+    new_cells.topology = cells.topology;
+    next_into(cells, &mut new_cells, rule);
+    new_cells
+}
+
+// Computes the next generation into `new_cells`, which must have the same size
+// as `cells`. Neighbours are counted 64 cells at a time with bit-sliced adders.
+pub fn next_into(cells: &Cells, new_cells: &mut Cells, rule: &Rule) {
+    let words = cells.words;
+    let last_mask = cells.last_word_mask();
+    new_cells.topology = cells.topology;
+
+    for y in 0..cells.size_y {
+        let upper = Row::neighbour(cells, y as i64 - 1);
+        let middle = Row::Normal(cells.row(y));
+        let lower = Row::neighbour(cells, y as i64 + 1);
+        let new_row = new_cells.row_mut(y);
 
-    for x in 0..cells.size_x {
-        for y in 0..cells.size_y {
-            let c = count_alive_around(&cells, x, y);
-            if cells.is_alive(x, y) && c == 2 || c == 3 {
-                new_cells.make_alive(x, y);
+        for (i, new_word) in new_row.iter_mut().enumerate() {
+            let (upper_w, upper_c, upper_e) = upper.shifted(cells, i);
+            let (middle_w, alive, middle_e) = middle.shifted(cells, i);
+            let (lower_w, lower_c, lower_e) = lower.shifted(cells, i);
+
+            let (s0, c0) = full_add(upper_w, upper_c, upper_e);
+            let (s1, c1) = full_add(middle_w, middle_e, lower_c);
+            let (s2, c2) = (lower_w ^ lower_e, lower_w & lower_e);
+            let (bit0, c3) = full_add(s0, s1, s2);
+            let (t0, t1) = full_add(c0, c1, c2);
+            let (bit1, c4) = (t0 ^ c3, t0 & c3);
+            let (bit2, bit3) = (t1 ^ c4, t1 & c4);
+
+            let mut word = 0;
+            for n in 0..9 {
+                if !rule.birth[n] && !rule.survival[n] {
+                    continue;
+                }
+                let count = select(bit0, n & 1) & select(bit1, n & 2) & select(bit2, n & 4) & select(bit3, n & 8);
+                if rule.birth[n] {
+                    word |= count & !alive;
+                }
+                if rule.survival[n] {
+                    word |= count & alive;
+                }
             }
+            *new_word = if i == words - 1 { word & last_mask } else { word };
         }
     }
+}
 
-    new_cells
+// A row of neighbours as seen from an adjacent row. Across the top and bottom
+// edges it may be missing (dead edges) or mirrored (Klein bottle).
+enum Row<'a> {
+    Dead,
+    Normal(&'a [u64]),
+    Reversed(&'a [u64]),
 }
 
+impl<'a> Row<'a> {
+    fn neighbour(cells: &'a Cells, y: i64) -> Row<'a> {
+        let sy = cells.size_y as i64;
+        if y >= 0 && y < sy {
+            return Row::Normal(cells.row(y as usize));
+        }
+        match cells.topology {
+            Topology::Torus | Topology::Vertical => Row::Normal(cells.row(cells.cell_y(y))),
+            Topology::KleinBottle => Row::Reversed(cells.row(cells.cell_y(y))),
+            Topology::Dead | Topology::Horizontal => Row::Dead,
+        }
+    }
 
+    fn word(&self, cells: &Cells, i: usize) -> u64 {
+        match *self {
+            Row::Dead => 0,
+            Row::Normal(row) => row[i],
+            Row::Reversed(row) => {
+                // Bit k of the word is the cell size_x - 1 - (64 * i + k), so
+                // take the 64 cells ending there and reverse them.
+                let start = cells.size_x as i64 - WORD_BITS as i64 * (i as i64 + 1);
+                let window = if start < 0 {
+                    row[0] << (-start as usize)
+                } else {
+                    let q = start as usize / WORD_BITS;
+                    let r = start as usize % WORD_BITS;
+                    if r == 0 {
+                        row[q]
+                    } else if q + 1 < row.len() {
+                        (row[q] >> r) | (row[q + 1] << (WORD_BITS - r))
+                    } else {
+                        row[q] >> r
+                    }
+                };
+                window.reverse_bits()
+            },
+        }
+    }
+
+    // Returns the i-th word shifted so that every bit holds its west neighbour,
+    // the word itself, and the word shifted so that every bit holds its east
+    // neighbour.
+    fn shifted(&self, cells: &Cells, i: usize) -> (u64, u64, u64) {
+        if let Row::Dead = *self {
+            return (0, 0, 0);
+        }
+        let last = cells.words - 1;
+        let last_bit = (cells.size_x - 1) % WORD_BITS;
+        let wraps = cells.topology.wraps_x();
+        let word = self.word(cells, i);
+
+        let west_carry = if i > 0 {
+            self.word(cells, i - 1) >> (WORD_BITS - 1)
+        } else if wraps {
+            (self.word(cells, last) >> last_bit) & 1
+        } else {
+            0
+        };
+        let east_carry = if i < last {
+            self.word(cells, i + 1) << (WORD_BITS - 1)
+        } else if wraps {
+            (self.word(cells, 0) & 1) << last_bit
+        } else {
+            0
+        };
+
+        ((word << 1) | west_carry, word, (word >> 1) | east_carry)
+    }
+}
+
+fn full_add(a: u64, b: u64, c: u64) -> (u64, u64) {
+    let t = a ^ b;
+    (t ^ c, (a & b) | (t & c))
+}
+
+fn select(bits: u64, set: usize) -> u64 {
+    if set == 0 { !bits } else { bits }
+}
+
+#[cfg(test)]
 fn count_alive_around(cells: &Cells, x: usize, y: usize) -> i32 {
     let mut count = 0;
     let left = (x as i32 - 1) as usize;
@@ -71,8 +195,30 @@ fn test_next() {
         vec![0, 0, 0, 0, 0, 0],
     ]);
 
-    assert_eq!(expected, next(&cells));
-    assert_eq!(cells, next(&expected));
+    assert_eq!(expected, next(&cells, &Rule::conway()));
+    assert_eq!(cells, next(&expected, &Rule::conway()));
+}
+
+#[test]
+fn test_next_with_rule() {
+    let cells = Cells::from_vec(vec![
+        vec![0, 0, 0, 0, 0],
+        vec![0, 0, 0, 0, 0],
+        vec![0, 1, 1, 0, 0],
+        vec![0, 0, 0, 0, 0],
+        vec![0, 0, 0, 0, 0],
+    ]);
+
+    let expected = Cells::from_vec(vec![
+        vec![0, 0, 0, 0, 0],
+        vec![0, 1, 1, 0, 0],
+        vec![0, 0, 0, 0, 0],
+        vec![0, 1, 1, 0, 0],
+        vec![0, 0, 0, 0, 0],
+    ]);
+
+    assert_eq!(expected, next(&cells, &"B2/S".parse().unwrap()));
+    assert_eq!(Cells::new(5, 5), next(&cells, &Rule::conway()));
 }
 
 #[test]
@@ -102,3 +248,76 @@ fn test_count_alive_around() {
     assert_eq!(1, count_alive_around(&cells, 2, 4));
     assert_eq!(1, count_alive_around(&cells, 3, 4));
 }
+
+#[test]
+fn test_next_wraps_around() {
+    for &(size_x, size_y) in &[(6, 5), (64, 5), (70, 6), (130, 7)] {
+        let mut cells = Cells::new(size_x, size_y);
+        cells.make_alive(size_x - 1, 0);
+        cells.make_alive(0, 0);
+        cells.make_alive(1, size_y - 1);
+
+        let mut expected = Cells::new(size_x, size_y);
+        expected.make_alive(0, size_y - 1);
+        expected.make_alive(0, 0);
+
+        assert_eq!(expected, next(&cells, &Rule::conway()), "{}x{}", size_x, size_y);
+    }
+}
+
+#[test]
+fn test_next_matches_count_alive_around() {
+    let rules: Vec<Rule> = vec![Rule::conway(), "B36/S23".parse().unwrap(), "B3678/S34678".parse().unwrap()];
+    let topologies = [Topology::Torus, Topology::Dead, Topology::Horizontal, Topology::Vertical, Topology::KleinBottle];
+
+    for &(size_x, size_y) in &[(20, 7), (75, 9), (128, 6)] {
+        let mut cells = Cells::new(size_x, size_y);
+        for x in 0..cells.size_x {
+            for y in 0..cells.size_y {
+                if (x * 7 + y * 13 + x * y) % 5 < 2 {
+                    cells.make_alive(x, y);
+                }
+            }
+        }
+
+        for rule in &rules {
+            for &topology in &topologies {
+                cells.topology = topology;
+                let mut expected = Cells::new(cells.size_x, cells.size_y);
+                expected.topology = topology;
+                for x in 0..cells.size_x {
+                    for y in 0..cells.size_y {
+                        if rule.next_state(cells.is_alive(x, y), count_alive_around(&cells, x, y) as usize) {
+                            expected.make_alive(x, y);
+                        }
+                    }
+                }
+
+                assert_eq!(expected, next(&cells, rule), "{}x{} {} {:?}", size_x, size_y, rule, topology);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_next_with_dead_edges() {
+    let mut cells = Cells::from_vec(vec![
+        vec![0, 1, 1, 1, 0],
+        vec![0, 0, 0, 0, 0],
+        vec![0, 0, 0, 0, 0],
+        vec![0, 0, 0, 0, 0],
+        vec![0, 0, 0, 0, 0],
+    ]);
+    cells.topology = Topology::Dead;
+
+    let mut expected = Cells::from_vec(vec![
+        vec![0, 0, 1, 0, 0],
+        vec![0, 0, 1, 0, 0],
+        vec![0, 0, 0, 0, 0],
+        vec![0, 0, 0, 0, 0],
+        vec![0, 0, 0, 0, 0],
+    ]);
+    expected.topology = Topology::Dead;
+
+    assert_eq!(expected, next(&cells, &Rule::conway()));
+}
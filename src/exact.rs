@@ -0,0 +1,296 @@
+use compound::*;
+use fitness::*;
+use mold::*;
+use optimizer::*;
+use primes::*;
+#[cfg(test)]
+use randomizer::*;
+use rule::*;
+
+// Finds the best placement of primes over a mold by branch and bound.
+// Primes never overlap, so with an additive fitness like `Weighted` the score
+// of a compound is the sum of the scores of its primes, and every placement can
+// be scored on its own up front. Other fitnesses are approximated by the gain
+// of each prime on an empty mold. The search gives up after visiting
+// `node_limit` nodes and returns the best compound found so far.
+pub struct BranchAndBound {
+    pub node_limit: usize,
+    pub primes: Vec<Prime<'static>>,
+    pub fitness: Box<dyn Fitness>,
+}
+
+// A prime placed with its top left corner on a given cell.
+struct Candidate {
+    prime: Prime<'static>,
+    gain: f64,
+}
+
+struct Search {
+    size: usize,
+    candidates: Vec<Vec<Candidate>>,
+    // The most any placement could gain per cell it covers.
+    upper: Vec<f64>,
+    occupied: Vec<bool>,
+    current: Vec<(usize, Prime<'static>)>,
+    best: Vec<(usize, Prime<'static>)>,
+    best_gain: f64,
+    nodes: usize,
+    node_limit: usize,
+}
+
+impl Optimizer for BranchAndBound {
+    fn optimize(&mut self, mold: Mold) -> Compound {
+        let size = mold.font_size;
+        let mut search = Search {
+            size: size,
+            candidates: Vec::new(),
+            upper: vec![0.0; size * size],
+            occupied: vec![false; size * size],
+            current: Vec::new(),
+            best: Vec::new(),
+            best_gain: 0.0,
+            nodes: 0,
+            node_limit: self.node_limit,
+        };
+
+        let empty = self.fitness.score(&mold, &Compound::new(size));
+        for i in 0..size * size {
+            let candidates = self.candidates(&mold, empty, i % size, i / size);
+            for c in &candidates {
+                let s = c.prime.exclusive_size;
+                let density = c.gain / (s * s) as f64;
+                for y in i / size..i / size + s {
+                    for x in i % size..i % size + s {
+                        let u = &mut search.upper[y * size + x];
+                        *u = u.max(density);
+                    }
+                }
+            }
+            search.candidates.push(candidates);
+        }
+
+        search.greedy();
+        let remaining = search.upper.iter().sum();
+        search.branch(0, 0.0, remaining);
+
+        let mut pattern = Compound::new(size);
+        for &(i, ref p) in &search.best {
+            pattern.set(p.clone(), i % size, i / size);
+        }
+        pattern
+    }
+
+    fn set_fitness(&mut self, fitness: Box<dyn Fitness>) {
+        self.fitness = fitness;
+    }
+
+    fn set_primes(&mut self, primes: Vec<Prime<'static>>) {
+        self.primes = primes;
+    }
+}
+
+impl BranchAndBound {
+    pub fn new(node_limit: usize, rule: &Rule) -> Result<Self, String> {
+        Ok(BranchAndBound {
+            node_limit: node_limit,
+            primes: stable_primes(rule)?,
+            fitness: default_fitness(),
+        })
+    }
+
+    // Every prime that fits inside the mold at (x, y) and gains something,
+    // best first. Primes that miss the glyph entirely are not even scored.
+    fn candidates(&self, mold: &Mold, empty: f64, x: usize, y: usize) -> Vec<Candidate> {
+        let mut candidates: Vec<Candidate> = self.primes.iter()
+            .filter(|p| x + p.exclusive_size <= mold.font_size && y + p.exclusive_size <= mold.font_size)
+            .filter(|p| touches(mold, p, x, y))
+            .map(|p| Candidate { prime: p.clone(), gain: self.gain(mold, empty, p, x, y) })
+            .filter(|c| c.gain > 0.0)
+            .collect();
+        candidates.sort_by(|a, b| b.gain.total_cmp(&a.gain));
+        candidates
+    }
+
+    fn gain(&self, mold: &Mold, empty: f64, prime: &Prime<'static>, x: usize, y: usize) -> f64 {
+        let mut pattern = Compound::new(mold.font_size);
+        pattern.set(prime.clone(), x, y);
+        self.fitness.score(mold, &pattern) - empty
+    }
+}
+
+impl Search {
+    // Visits the cells in row-major order. A prime anchored on cell `i` only
+    // covers cells from `i` on, so `remaining`, the upper bound summed over
+    // the free cells from `i` on, bounds what is still to be gained.
+    fn branch(&mut self, i: usize, gain: f64, remaining: f64) {
+        if self.best_gain < gain {
+            self.best_gain = gain;
+            self.best = self.current.clone();
+        }
+        if i == self.size * self.size || self.nodes >= self.node_limit {
+            return;
+        }
+        if gain + remaining <= self.best_gain + 1e-9 {
+            return;
+        }
+        self.nodes += 1;
+
+        if self.occupied[i] {
+            self.branch(i + 1, gain, remaining);
+            return;
+        }
+
+        for k in 0..self.candidates[i].len() {
+            let (prime, prime_gain) = (self.candidates[i][k].prime.clone(), self.candidates[i][k].gain);
+            if !self.is_free(i, prime.exclusive_size) {
+                continue;
+            }
+            let covered = self.fill(i, prime.exclusive_size, true);
+            let size = prime.exclusive_size;
+            self.current.push((i, prime));
+            self.branch(i + 1, gain + prime_gain, remaining - covered);
+            self.current.pop();
+            self.fill(i, size, false);
+        }
+
+        let upper = self.upper[i];
+        self.branch(i + 1, gain, remaining - upper);
+    }
+
+    // Starts from the better of two greedy tilings, placing the candidates
+    // with the largest gain or with the largest gain per cell first wherever
+    // they still fit. A good starting tiling lets the search prune far more of
+    // the tree, and is what is returned if the search hits the node limit.
+    fn greedy(&mut self) {
+        let mut all = Vec::new();
+        for (i, candidates) in self.candidates.iter().enumerate() {
+            for c in candidates {
+                let s = c.prime.exclusive_size;
+                all.push((c.gain, c.gain / (s * s) as f64, i, c.prime.clone()));
+            }
+        }
+
+        let mut tilings = Vec::new();
+        for &by_density in &[false, true] {
+            all.sort_by(|a, b| {
+                let order = if by_density { b.1.total_cmp(&a.1) } else { b.0.total_cmp(&a.0) };
+                order.then(a.2.cmp(&b.2))
+            });
+            let (mut tiling, mut total) = (Vec::new(), 0.0);
+            for &(gain, _, i, ref prime) in &all {
+                if self.is_free(i, prime.exclusive_size) {
+                    self.fill(i, prime.exclusive_size, true);
+                    tiling.push((i, prime.clone()));
+                    total += gain;
+                }
+            }
+            self.occupied = vec![false; self.size * self.size];
+            tilings.push((total, tiling));
+        }
+        let (gain, tiling) = tilings.into_iter().max_by(|a, b| a.0.total_cmp(&b.0)).unwrap();
+        self.best_gain = gain;
+        self.best = tiling;
+    }
+
+    fn is_free(&self, i: usize, s: usize) -> bool {
+        let (x, y) = (i % self.size, i / self.size);
+        (y..y + s).all(|y| (x..x + s).all(|x| !self.occupied[y * self.size + x]))
+    }
+
+    // Marks or clears the box of a prime and returns the upper bound it covers.
+    fn fill(&mut self, i: usize, s: usize, occupied: bool) -> f64 {
+        let (x, y) = (i % self.size, i / self.size);
+        let mut covered = 0.0;
+        for y in y..y + s {
+            for x in x..x + s {
+                self.occupied[y * self.size + x] = occupied;
+                covered += self.upper[y * self.size + x];
+            }
+        }
+        covered
+    }
+}
+
+fn touches(mold: &Mold, prime: &Prime, x: usize, y: usize) -> bool {
+    (0..prime.exclusive_size).any(|x2| {
+        (0..prime.exclusive_size).any(|y2| prime.occupied[x2][y2] > 0 && mold.target.is_alive(x + x2, y + y2))
+    })
+}
+
+#[test]
+fn test_optimize_is_deterministic() {
+    let optimize = || BranchAndBound::new(10000, &Rule::conway()).unwrap().optimize(Mold::from_char('R', 20));
+
+    assert_eq!(optimize(), optimize());
+}
+
+#[test]
+fn test_optimize_places_primes_that_fit() {
+    for c in "AgW8x".chars() {
+        let mut optimizer = BranchAndBound::new(10000, &Rule::conway()).unwrap();
+        let compound = optimizer.optimize(Mold::from_char(c, 20));
+
+        let mut rebuilt = Compound::new(compound.font_size);
+        for (x, y, p) in compound.placements() {
+            assert!(rebuilt.fits(&p, x, y));
+            rebuilt.set(p, x, y);
+        }
+    }
+}
+
+#[test]
+fn test_optimize_fills_a_square() {
+    let mut mold = Mold::from_char(' ', 8);
+    mold.target.make_alive_square(1, 1, 2);
+    mold.target.make_alive_square(5, 5, 2);
+    let mut optimizer = BranchAndBound::new(10000, &Rule::conway()).unwrap();
+    let compound = optimizer.optimize(mold);
+
+    assert_eq!(vec![(0, 0, BLOCK), (4, 4, BLOCK)], compound.placements());
+}
+
+#[test]
+fn test_optimize_beats_gradient_descent() {
+    for c in "AgW8x".chars() {
+        let mold = Mold::from_char(c, 20);
+        let mut exact = BranchAndBound::new(20000, &Rule::conway()).unwrap();
+        let exact_score = evaluate(&mold, &exact.optimize(Mold::from_char(c, 20)));
+        for seed in 0..4 {
+            let mut gradient = GradientDescent::new(SplitMix::from_seed(seed), 5, &Rule::conway()).unwrap();
+            let gradient_score = evaluate(&mold, &gradient.optimize(Mold::from_char(c, 20)));
+            assert!(exact_score >= gradient_score, "{}: {} < {}", c, exact_score, gradient_score);
+        }
+    }
+}
+
+#[test]
+fn test_gains_add_up_to_score() {
+    let mold = Mold::from_char('A', 20);
+    let optimizer = BranchAndBound::new(10000, &Rule::conway()).unwrap();
+    let mut compound = Compound::new(20);
+    let mut total = 0.0;
+    for (x, y, p) in [(0, 0, BLOCK), (4, 5, TUB), (10, 2, BEACON), (14, 14, TOAD)] {
+        total += optimizer.gain(&mold, 0.0, &p, x, y);
+        compound.set(p, x, y);
+    }
+
+    assert_eq!(evaluate(&mold, &compound), total);
+}
+
+#[test]
+fn test_optimize_with_fitness() {
+    let mut optimizer = BranchAndBound::new(10000, &Rule::conway()).unwrap();
+    optimizer.set_fitness(Box::new(IoU));
+    let compound = optimizer.optimize(Mold::from_char('T', 20));
+
+    assert!(!compound.placements().is_empty());
+}
+
+#[test]
+fn test_optimize_with_phase_scoring() {
+    let mut optimizer = BranchAndBound::new(10000, &Rule::conway()).unwrap();
+    optimizer.set_fitness(Box::new(PhaseScored::new(Box::new(EdgeAware::new(2.0, 1.0, 0.5)), PhaseScoring::Worst)));
+    let compound = optimizer.optimize(Mold::from_char('T', 20));
+
+    assert!(!compound.placements().is_empty());
+}
@@ -0,0 +1,313 @@
+use std::str::FromStr;
+use cells::*;
+use compound::*;
+use config::*;
+#[cfg(test)]
+use evolve::*;
+use mold::*;
+#[cfg(test)]
+use primes::*;
+#[cfg(test)]
+use rule::*;
+
+// Scores how closely a compound draws the glyph of a mold. Higher is better.
+pub trait Fitness {
+    fn score_cells(&self, mold: &Mold, cells: &Cells) -> f64;
+
+    fn score(&self, mold: &Mold, pattern: &Compound) -> f64 {
+        self.score_cells(mold, &pattern.to_cells())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FitnessKind {
+    Weighted,
+    IoU,
+    EdgeAware,
+}
+
+impl FromStr for FitnessKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<FitnessKind, String> {
+        match s.to_lowercase().as_str() {
+            "weighted" => Ok(FitnessKind::Weighted),
+            "iou" => Ok(FitnessKind::IoU),
+            "edge" => Ok(FitnessKind::EdgeAware),
+            _ => Err(format!("unknown fitness: {}", s)),
+        }
+    }
+}
+
+// How the scores of the phases of an oscillating compound are combined.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PhaseScoring {
+    First,
+    Average,
+    Worst,
+}
+
+impl FromStr for PhaseScoring {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<PhaseScoring, String> {
+        match s.to_lowercase().as_str() {
+            "first" => Ok(PhaseScoring::First),
+            "average" => Ok(PhaseScoring::Average),
+            "worst" => Ok(PhaseScoring::Worst),
+            _ => Err(format!("unknown phase scoring: {}", s)),
+        }
+    }
+}
+
+pub fn from_config(config: &Config) -> Box<dyn Fitness> {
+    let fitness: Box<dyn Fitness> = match config.fitness {
+        FitnessKind::Weighted => default_fitness(),
+        FitnessKind::IoU => Box::new(IoU),
+        FitnessKind::EdgeAware => Box::new(EdgeAware::new(2.0, 1.0, 0.5)),
+    };
+    match config.phase_scoring {
+        PhaseScoring::First => fitness,
+        scoring => Box::new(PhaseScored::new(fitness, scoring)),
+    }
+}
+
+pub fn default_fitness() -> Box<dyn Fitness> {
+    Box::new(Weighted::new(1.0, 0.5))
+}
+
+// Rewards cells inside the glyph and penalizes cells outside of it.
+pub struct Weighted {
+    pub intersect: f64,
+    pub difference: f64,
+}
+
+impl Weighted {
+    pub fn new(intersect: f64, difference: f64) -> Weighted {
+        Weighted {
+            intersect: intersect,
+            difference: difference,
+        }
+    }
+}
+
+impl Fitness for Weighted {
+    fn score_cells(&self, mold: &Mold, cells: &Cells) -> f64 {
+        mold.intersect(cells) as f64 * self.intersect - mold.difference(cells) as f64 * self.difference
+    }
+}
+
+// Intersection over union of the glyph and the alive cells, from 0 to 1.
+pub struct IoU;
+
+impl Fitness for IoU {
+    fn score_cells(&self, mold: &Mold, cells: &Cells) -> f64 {
+        let intersect = mold.intersect(cells);
+        let union = mold.target.count_alive() + mold.difference(cells);
+        if union == 0 {
+            return 0.0;
+        }
+        intersect as f64 / union as f64
+    }
+}
+
+// Like `Weighted`, but cells on the outline of the glyph are worth more than
+// cells inside it, so that thin strokes and corners get drawn.
+pub struct EdgeAware {
+    pub edge: f64,
+    pub interior: f64,
+    pub difference: f64,
+}
+
+impl EdgeAware {
+    pub fn new(edge: f64, interior: f64, difference: f64) -> EdgeAware {
+        EdgeAware {
+            edge: edge,
+            interior: interior,
+            difference: difference,
+        }
+    }
+}
+
+impl Fitness for EdgeAware {
+    fn score_cells(&self, mold: &Mold, cells: &Cells) -> f64 {
+        let target = &mold.target;
+        let mut score = 0.0;
+        for x in 0..target.size_x {
+            for y in 0..target.size_y {
+                if !cells.is_alive(x, y) {
+                    continue;
+                }
+                if !target.is_alive(x, y) {
+                    score -= self.difference;
+                } else if is_edge(target, x, y) {
+                    score += self.edge;
+                } else {
+                    score += self.interior;
+                }
+            }
+        }
+        score
+    }
+}
+
+fn is_edge(target: &Cells, x: usize, y: usize) -> bool {
+    let inside = |x: i64, y: i64| {
+        x >= 0 && y >= 0 && (x as usize) < target.size_x && (y as usize) < target.size_y
+            && target.is_alive(x as usize, y as usize)
+    };
+    let (x, y) = (x as i64, y as i64);
+    !(inside(x - 1, y) && inside(x + 1, y) && inside(x, y - 1) && inside(x, y + 1))
+}
+
+// Combines the scores of another fitness over the phases of the compound as
+// `scoring` says, reading each phase from the primes instead of simulating.
+pub struct PhaseScored {
+    pub inner: Box<dyn Fitness>,
+    pub scoring: PhaseScoring,
+}
+
+impl PhaseScored {
+    pub fn new(inner: Box<dyn Fitness>, scoring: PhaseScoring) -> PhaseScored {
+        PhaseScored {
+            inner: inner,
+            scoring: scoring,
+        }
+    }
+}
+
+impl Fitness for PhaseScored {
+    fn score_cells(&self, mold: &Mold, cells: &Cells) -> f64 {
+        self.inner.score_cells(mold, cells)
+    }
+
+    fn score(&self, mold: &Mold, pattern: &Compound) -> f64 {
+        let period = match self.scoring {
+            PhaseScoring::First => 1,
+            _ => pattern.period(),
+        };
+        let scores = (0..period).map(|phase| self.inner.score_cells(mold, &pattern.to_cells_at(phase)));
+        match self.scoring {
+            PhaseScoring::Worst => scores.fold(f64::INFINITY, f64::min),
+            _ => scores.sum::<f64>() / period as f64,
+        }
+    }
+}
+
+#[cfg(test)]
+fn square_mold() -> Mold {
+    let mut target = Cells::new(10, 10);
+    target.make_alive_square(1, 1, 3);
+    Mold {
+        font_size: 10,
+        target: target,
+    }
+}
+
+#[test]
+fn test_weighted() {
+    let mut pattern = Compound::new(10);
+    pattern.set(BLOCK, 1, 1);
+    pattern.set(BLOCK, 5, 5);
+
+    assert_eq!(4.0 - 0.5 * 4.0, Weighted::new(1.0, 0.5).score(&square_mold(), &pattern));
+    assert_eq!(8.0 - 4.0, Weighted::new(2.0, 1.0).score(&square_mold(), &pattern));
+}
+
+#[test]
+fn test_iou() {
+    let mut pattern = Compound::new(10);
+
+    assert_eq!(0.0, IoU.score(&square_mold(), &pattern));
+    pattern.set(BLOCK, 0, 0);
+    assert_eq!(4.0 / 9.0, IoU.score(&square_mold(), &pattern));
+    pattern.set(BLOCK, 5, 5);
+    assert_eq!(4.0 / 13.0, IoU.score(&square_mold(), &pattern));
+}
+
+#[test]
+fn test_edge_aware() {
+    let fitness = EdgeAware::new(2.0, 1.0, 0.5);
+    let mut pattern = Compound::new(10);
+    pattern.set(BLOCK, 0, 0);
+
+    // Of the block's cells, only (2, 2) is in the middle of the square.
+    assert_eq!(3.0 * 2.0 + 1.0, fitness.score(&square_mold(), &pattern));
+    pattern.set(BLOCK, 5, 5);
+    assert_eq!(7.0 - 2.0, fitness.score(&square_mold(), &pattern));
+}
+
+#[test]
+fn test_phase_scored() {
+    let mut target = Cells::new(10, 10);
+    target.make_alive_square(1, 2, 3);
+    let mold = Mold {
+        font_size: 10,
+        target: target,
+    };
+    let weighted = Weighted::new(1.0, 0.5);
+    let mut pattern = Compound::new(10);
+    pattern.set(BLINKER, 0, 0);
+
+    let mut cells = pattern.to_cells();
+    let phase_zero = weighted.score_cells(&mold, &cells);
+    cells = next(&cells, &Rule::conway());
+    let phase_one = weighted.score_cells(&mold, &cells);
+
+    assert!(phase_zero < phase_one);
+    let score = |scoring| PhaseScored::new(Box::new(Weighted::new(1.0, 0.5)), scoring).score(&mold, &pattern);
+    assert_eq!(phase_zero, score(PhaseScoring::First));
+    assert_eq!((phase_zero + phase_one) / 2.0, score(PhaseScoring::Average));
+    assert_eq!(phase_zero, score(PhaseScoring::Worst));
+
+    let mut block = Compound::new(10);
+    block.set(BLOCK, 0, 1);
+    let average = PhaseScored::new(Box::new(Weighted::new(1.0, 0.5)), PhaseScoring::Average);
+    assert_eq!(weighted.score(&mold, &block), average.score(&mold, &block));
+}
+
+#[test]
+fn test_from_config() {
+    let mut config = Config::new();
+    let mut pattern = Compound::new(10);
+    pattern.set(BLINKER, 0, 0);
+    let mold = square_mold();
+    let average = PhaseScored::new(default_fitness(), PhaseScoring::Average).score(&mold, &pattern);
+
+    config.fitness = FitnessKind::Weighted;
+    config.phase_scoring = PhaseScoring::Average;
+    assert_eq!(average, from_config(&config).score(&mold, &pattern));
+    config.phase_scoring = PhaseScoring::First;
+    assert_eq!(default_fitness().score(&mold, &pattern), from_config(&config).score(&mold, &pattern));
+}
+
+#[test]
+fn test_phase_scored_long_cycles() {
+    let mut target = Cells::new(40, 40);
+    target.make_alive_square(0, 0, 40);
+    let mold = Mold {
+        font_size: 40,
+        target: target,
+    };
+    let mut pattern = Compound::new(40);
+    pattern.set(PENTADECATHLON, 0, 0);
+    pattern.set(FIGURE_EIGHT, 20, 0);
+    assert_eq!(120, pattern.period());
+
+    // Every one of the 120 phases counts, including the least legible one.
+    let weighted = Weighted::new(1.0, 0.0);
+    let scores: Vec<f64> = (0..120).map(|phase| weighted.score_cells(&mold, &pattern.to_cells_at(phase))).collect();
+    let average = scores.iter().sum::<f64>() / 120.0;
+    let worst = scores.iter().cloned().fold(f64::INFINITY, f64::min);
+    assert_eq!(average, PhaseScored::new(Box::new(Weighted::new(1.0, 0.0)), PhaseScoring::Average).score(&mold, &pattern));
+    assert_eq!(worst, PhaseScored::new(Box::new(weighted), PhaseScoring::Worst).score(&mold, &pattern));
+}
+
+#[test]
+fn test_fitness_from_str() {
+    assert_eq!(Ok(FitnessKind::IoU), "IoU".parse());
+    assert_eq!(Ok(FitnessKind::EdgeAware), "edge".parse());
+    assert!("area".parse::<FitnessKind>().is_err());
+    assert_eq!(Ok(PhaseScoring::Worst), "worst".parse());
+    assert!("best".parse::<PhaseScoring>().is_err());
+}
@@ -1,351 +1,443 @@
+// Glyphs are drawn on an em square of EM cells, with the baseline ASCENT rows
+// from the top.
+pub const EM: usize = 10;
+pub const ASCENT: usize = 8;
+
+#[derive(Clone, Copy)]
 pub struct Font<'a> {
-    pub val: &'a[&'a[i8; 10]; 10],
+    pub val: &'a[&'a[i8]],
+    // How far the next glyph starts, in columns of `val`.
+    pub width: usize,
+    // The number of rows of `val` above the baseline.
+    pub baseline: usize,
+    // Adjustments to `width` when the glyph is followed by the given character.
+    pub kerning: &'a[(char, i8)],
+}
+
+impl<'a> Font<'a> {
+    // The advance width in cells at the given font size.
+    pub fn advance(&self, next: Option<char>, font_size: usize) -> usize {
+        let kerning = self.kerning.iter()
+            .find(|&&(c, _)| Some(c) == next)
+            .map_or(0, |&(_, k)| k as i64);
+        let width = (self.width as i64 + kerning).max(0) as usize;
+        (width * font_size + EM / 2) / EM
+    }
 }
 
 pub const FONT_EMPTY: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-    ]
+        &[0, 0, 0],
+        &[0, 0, 0],
+        &[0, 0, 0],
+        &[0, 0, 0],
+        &[0, 0, 0],
+        &[0, 0, 0],
+        &[0, 0, 0],
+        &[0, 0, 0],
+        &[0, 0, 0],
+        &[0, 0, 0],
+    ],
+    width: 3,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_A: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 0, 1, 0, 0],
-        &[0, 1, 1, 0, 0, 0, 0, 1, 1, 0],
-        &[0, 1, 0, 0, 0, 0, 0, 0, 1, 0],
-    ]
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0, 0],
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+        &[0, 1, 1, 1, 1, 1, 1, 0],
+        &[0, 1, 0, 0, 0, 0, 1, 0],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 0, 0, 0, 0, 0, 0, 1],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[('T', -1), ('V', -1), ('W', -1), ('Y', -1)],
 };
 
 
 pub const FONT_B: Font<'static> = Font {
     val: &[
-        &[0, 0, 1, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 0, 0, 0],
-    ]
+        &[1, 1, 1, 1, 1, 0],
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 1, 0, 0, 0, 1],
+        &[1, 1, 0, 0, 0, 1],
+        &[1, 1, 0, 0, 0, 1],
+        &[1, 1, 1, 1, 1, 0],
+        &[1, 1, 0, 0, 0, 1],
+        &[1, 1, 0, 0, 0, 1],
+        &[1, 1, 0, 0, 0, 1],
+        &[1, 1, 1, 1, 1, 0],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_C: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 1, 1, 0, 0],
-        &[0, 1, 1, 0, 0, 0, 0, 1, 0, 0],
-        &[0, 1, 1, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 1, 1, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 1, 1, 0, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-    ]
+        &[0, 0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 1, 1, 0],
+        &[0, 1, 1, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1],
+        &[1, 1, 0, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0, 1],
+        &[0, 1, 1, 0, 0, 1, 1],
+        &[0, 0, 1, 1, 1, 1, 0],
+        &[0, 0, 0, 1, 1, 0, 0],
+    ],
+    width: 7,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_D: Font<'static> = Font {
     val: &[
-        &[0, 0, 1, 1, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 0, 0, 0, 0],
-    ]
+        &[1, 1, 1, 1, 0, 0],
+        &[1, 1, 1, 1, 1, 0],
+        &[1, 1, 0, 0, 1, 0],
+        &[1, 1, 0, 0, 0, 1],
+        &[1, 1, 0, 0, 0, 1],
+        &[1, 1, 0, 0, 0, 1],
+        &[1, 1, 0, 0, 0, 1],
+        &[1, 1, 0, 0, 1, 0],
+        &[1, 1, 1, 1, 1, 0],
+        &[1, 1, 1, 1, 0, 0],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_E: Font<'static> = Font {
     val: &[
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-    ]
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_F: Font<'static> = Font {
     val: &[
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 0, 0, 0],
-    ]
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 0],
+        &[1, 1, 1, 1, 1, 0],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[('a', -1), ('o', -1), ('e', -1), (',', -2), ('.', -2)],
 };
 
 pub const FONT_G: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 1, 1, 0, 0, 1, 1, 0, 0, 0],
-        &[0, 1, 1, 0, 0, 1, 1, 0, 0, 0],
-        &[0, 1, 1, 0, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 1, 0, 0, 0],
-    ]
+        &[0, 0, 0, 1, 1, 1, 0],
+        &[0, 0, 1, 1, 1, 1, 1],
+        &[0, 1, 1, 0, 0, 0, 1],
+        &[0, 1, 0, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 1, 1, 0],
+        &[1, 1, 0, 0, 1, 1, 0],
+        &[1, 1, 0, 0, 0, 0, 1],
+        &[0, 1, 1, 0, 0, 0, 1],
+        &[0, 0, 1, 1, 1, 1, 1],
+        &[0, 0, 0, 1, 1, 1, 0],
+    ],
+    width: 7,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_H: Font<'static> = Font {
     val: &[
-        &[0, 0, 1, 1, 0, 0, 0, 1, 1, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 1, 1, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 1, 1, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 1, 1, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 1, 1, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 1, 1, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 1, 1, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 1, 1, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 1, 1, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 1, 1, 0],
-    ]
+        &[1, 1, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 1, 1],
+        &[1, 1, 1, 1, 1, 1, 1],
+        &[1, 1, 1, 1, 1, 1, 1],
+        &[1, 1, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 1, 1],
+    ],
+    width: 7,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_I: Font<'static> = Font {
     val: &[
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-    ]
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 1, 1, 1, 1, 1],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 1, 1, 1, 1, 1],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_J: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 1, 1, 1, 1, 1, 1, 0],
-        &[0, 0, 0, 1, 1, 1, 1, 1, 1, 0],
-        &[0, 0, 0, 0, 0, 1, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 1, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 1, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 1, 1, 0, 0, 0],
-        &[0, 1, 0, 0, 0, 1, 1, 0, 0, 0],
-        &[0, 1, 1, 0, 0, 1, 1, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 0, 0, 0, 0, 0],
-    ]
+        &[0, 0, 1, 1, 1, 1, 1, 1],
+        &[0, 0, 1, 1, 1, 1, 1, 1],
+        &[0, 0, 0, 0, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 1, 1, 0, 0],
+        &[1, 0, 0, 0, 1, 1, 0, 0],
+        &[1, 1, 0, 0, 1, 1, 0, 0],
+        &[0, 1, 1, 1, 1, 0, 0, 0],
+        &[0, 0, 1, 1, 0, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_K: Font<'static> = Font {
     val: &[
-        &[0, 0, 1, 1, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 0, 1, 1, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 1, 1, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 1, 0, 0],
-    ]
+        &[1, 1, 0, 0, 0, 1],
+        &[1, 1, 0, 0, 1, 1],
+        &[1, 1, 0, 1, 1, 0],
+        &[1, 1, 1, 1, 0, 0],
+        &[1, 1, 1, 0, 0, 0],
+        &[1, 1, 1, 0, 0, 0],
+        &[1, 1, 1, 1, 0, 0],
+        &[1, 1, 0, 1, 1, 0],
+        &[1, 1, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 1],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_L: Font<'static> = Font {
     val: &[
-        &[0, 0, 1, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-    ]
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 1, 1, 1, 1, 1],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[('T', -2), ('V', -1), ('W', -1), ('Y', -2)],
 };
 
 pub const FONT_M: Font<'static> = Font {
     val: &[
-        &[0, 1, 1, 0, 0, 0, 0, 1, 1, 0],
-        &[0, 1, 1, 0, 0, 0, 0, 1, 1, 0],
-        &[0, 1, 1, 1, 0, 0, 1, 1, 1, 0],
-        &[0, 1, 1, 1, 0, 0, 1, 1, 1, 0],
-        &[0, 1, 1, 1, 1, 1, 1, 1, 1, 0],
-        &[0, 1, 1, 0, 1, 1, 0, 1, 1, 0],
-        &[0, 1, 1, 0, 1, 1, 0, 1, 1, 0],
-        &[0, 1, 1, 0, 0, 0, 0, 1, 1, 0],
-        &[0, 1, 1, 0, 0, 0, 0, 1, 1, 0],
-        &[0, 1, 1, 0, 0, 0, 0, 1, 1, 0],
-    ]
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 1, 0, 0, 1, 1, 1],
+        &[1, 1, 1, 0, 0, 1, 1, 1],
+        &[1, 1, 1, 1, 1, 1, 1, 1],
+        &[1, 1, 0, 1, 1, 0, 1, 1],
+        &[1, 1, 0, 1, 1, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_N: Font<'static> = Font {
     val: &[
-        &[0, 1, 1, 1, 0, 0, 0, 1, 1, 0],
-        &[0, 1, 1, 1, 0, 0, 0, 1, 1, 0],
-        &[0, 1, 1, 1, 0, 0, 0, 1, 1, 0],
-        &[0, 1, 1, 0, 1, 0, 0, 1, 1, 0],
-        &[0, 1, 1, 0, 1, 0, 0, 1, 1, 0],
-        &[0, 1, 1, 0, 1, 1, 0, 1, 1, 0],
-        &[0, 1, 1, 0, 0, 1, 0, 1, 1, 0],
-        &[0, 1, 1, 0, 0, 1, 1, 1, 1, 0],
-        &[0, 1, 1, 0, 0, 0, 1, 1, 1, 0],
-        &[0, 1, 1, 0, 0, 0, 1, 1, 1, 0],
-    ]
+        &[1, 1, 1, 0, 0, 0, 1, 1],
+        &[1, 1, 1, 0, 0, 0, 1, 1],
+        &[1, 1, 1, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 1, 0, 0, 1, 1],
+        &[1, 1, 0, 1, 0, 0, 1, 1],
+        &[1, 1, 0, 1, 1, 0, 1, 1],
+        &[1, 1, 0, 0, 1, 0, 1, 1],
+        &[1, 1, 0, 0, 1, 1, 1, 1],
+        &[1, 1, 0, 0, 0, 1, 1, 1],
+        &[1, 1, 0, 0, 0, 1, 1, 1],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_O: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 0, 1, 0, 0],
-        &[0, 1, 1, 0, 0, 0, 0, 1, 1, 0],
-        &[0, 1, 1, 0, 0, 0, 0, 1, 1, 0],
-        &[0, 1, 1, 0, 0, 0, 0, 1, 1, 0],
-        &[0, 1, 1, 0, 0, 0, 0, 1, 1, 0],
-        &[0, 0, 1, 0, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 1, 0, 0, 0],
-    ]
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+        &[0, 1, 1, 0, 0, 1, 1, 0],
+        &[0, 1, 0, 0, 0, 0, 1, 0],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[0, 1, 0, 0, 0, 0, 1, 0],
+        &[0, 1, 1, 0, 0, 1, 1, 0],
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_P: Font<'static> = Font {
     val: &[
-        &[0, 0, 1, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 1, 1, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 1, 1, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 1, 1, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 0, 0, 0],
-    ]
+        &[1, 1, 1, 1, 1, 0, 0],
+        &[1, 1, 1, 1, 1, 1, 0],
+        &[1, 1, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 1, 1],
+        &[1, 1, 1, 1, 1, 1, 0],
+        &[1, 1, 1, 1, 1, 0, 0],
+        &[1, 1, 0, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0, 0],
+    ],
+    width: 7,
+    baseline: 8,
+    kerning: &[('A', -1), (',', -2), ('.', -2)],
 };
 
 pub const FONT_Q: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 0, 1, 0, 0],
-        &[0, 1, 1, 0, 0, 0, 0, 1, 1, 0],
-        &[0, 1, 1, 0, 0, 0, 0, 1, 1, 0],
-        &[0, 1, 1, 0, 0, 1, 0, 1, 1, 0],
-        &[0, 1, 1, 0, 0, 1, 1, 1, 1, 0],
-        &[0, 0, 1, 0, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 1, 1, 1, 0],
-        &[0, 0, 0, 1, 1, 1, 1, 0, 1, 0],
-    ]
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+        &[0, 1, 1, 0, 0, 1, 1, 0],
+        &[0, 1, 0, 0, 0, 0, 1, 0],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 1, 0, 1, 1],
+        &[1, 1, 0, 0, 1, 1, 1, 1],
+        &[0, 1, 0, 0, 0, 1, 1, 0],
+        &[0, 1, 1, 0, 0, 1, 1, 1],
+        &[0, 0, 1, 1, 1, 1, 0, 1],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_R: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 1, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 0, 1, 1, 0],
-    ]
+        &[0, 1, 1, 1, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 0, 0],
+        &[1, 0, 0, 0, 1, 0, 0],
+        &[1, 0, 0, 0, 0, 1, 0],
+        &[1, 0, 0, 0, 1, 1, 0],
+        &[1, 1, 1, 1, 1, 0, 0],
+        &[1, 1, 1, 1, 0, 0, 0],
+        &[1, 0, 0, 0, 1, 0, 0],
+        &[1, 0, 0, 0, 1, 1, 0],
+        &[1, 0, 0, 0, 0, 1, 1],
+    ],
+    width: 7,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_S: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-    ]
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 1, 1, 1, 1, 0],
+        &[1, 1, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 1],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[1, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 1, 1],
+        &[0, 1, 1, 1, 1, 0],
+        &[0, 0, 1, 1, 0, 0],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_T: Font<'static> = Font {
     val: &[
-        &[0, 1, 1, 1, 1, 1, 1, 1, 1, 0],
-        &[0, 1, 1, 1, 1, 1, 1, 1, 1, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-    ]
+        &[1, 1, 1, 1, 1, 1, 1, 1],
+        &[1, 1, 1, 1, 1, 1, 1, 1],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[('A', -1), ('a', -1), ('e', -1), ('o', -1), (',', -2), ('.', -2)],
 };
 
 pub const FONT_U: Font<'static> = Font {
     val: &[
-        &[0, 1, 1, 0, 0, 0, 0, 1, 1, 0],
-        &[0, 1, 1, 0, 0, 0, 0, 1, 1, 0],
-        &[0, 1, 1, 0, 0, 0, 0, 1, 1, 0],
-        &[0, 1, 1, 0, 0, 0, 0, 1, 1, 0],
-        &[0, 1, 1, 0, 0, 0, 0, 1, 1, 0],
-        &[0, 1, 1, 0, 0, 0, 0, 1, 1, 0],
-        &[0, 1, 1, 0, 0, 0, 0, 1, 1, 0],
-        &[0, 1, 1, 1, 0, 0, 1, 1, 1, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-    ]
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 1, 0, 0, 1, 1, 1],
+        &[0, 1, 1, 1, 1, 1, 1, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_V: Font<'static> = Font {
     val: &[
-        &[0, 1, 0, 0, 0, 0, 0, 0, 1, 0],
-        &[0, 1, 1, 0, 0, 0, 0, 1, 1, 0],
-        &[0, 1, 1, 0, 0, 0, 0, 1, 1, 0],
-        &[0, 0, 1, 0, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-    ]
+        &[1, 0, 0, 0, 0, 0, 0, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[0, 1, 0, 0, 0, 0, 1, 0],
+        &[0, 1, 1, 0, 0, 1, 1, 0],
+        &[0, 0, 1, 0, 0, 1, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0, 0],
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[('A', -1), ('a', -1), ('e', -1), ('o', -1)],
 };
 
 pub const FONT_W: Font<'static> = Font {
@@ -360,590 +452,3040 @@ pub const FONT_W: Font<'static> = Font {
         &[0, 0, 1, 1, 0, 0, 1, 1, 0, 0],
         &[0, 0, 1, 0, 0, 0, 0, 1, 0, 0],
         &[0, 0, 1, 0, 0, 0, 0, 1, 0, 0],
-    ]
+    ],
+    width: 10,
+    baseline: 8,
+    kerning: &[('A', -1), ('a', -1), ('e', -1), ('o', -1)],
 };
 
 pub const FONT_X: Font<'static> = Font {
     val: &[
-        &[0, 1, 1, 0, 0, 0, 0, 1, 1, 0],
-        &[0, 1, 1, 1, 0, 0, 1, 1, 1, 0],
-        &[0, 0, 1, 1, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 1, 1, 0, 0],
-        &[0, 1, 1, 1, 0, 0, 1, 1, 1, 0],
-        &[0, 1, 1, 0, 0, 0, 0, 1, 1, 0],
-    ]
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 1, 0, 0, 1, 1, 1],
+        &[0, 1, 1, 0, 0, 1, 1, 0],
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+        &[0, 1, 1, 0, 0, 1, 1, 0],
+        &[1, 1, 1, 0, 0, 1, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_Y: Font<'static> = Font {
     val: &[
-        &[0, 1, 0, 0, 0, 0, 0, 0, 1, 0],
-        &[0, 1, 1, 0, 0, 0, 0, 1, 1, 0],
-        &[0, 0, 1, 0, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-    ]
+        &[1, 0, 0, 0, 0, 0, 0, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[0, 1, 0, 0, 0, 0, 1, 0],
+        &[0, 1, 1, 0, 0, 1, 1, 0],
+        &[0, 0, 1, 0, 0, 1, 0, 0],
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[('A', -1), ('a', -1), ('e', -1), ('o', -1)],
 };
 
 pub const FONT_Z: Font<'static> = Font {
     val: &[
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-    ]
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 1, 1, 1, 1, 1],
+        &[0, 0, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 1, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 1, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 1, 1, 1, 1, 1],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_CASE_A: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 1, 0, 0, 0],
-        &[0, 1, 0, 0, 0, 0, 1, 0, 0, 0],
-        &[0, 1, 0, 0, 0, 0, 1, 0, 0, 0],
-        &[0, 1, 0, 0, 0, 0, 1, 0, 0, 0],
-        &[0, 1, 0, 0, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 0, 1, 1, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-    ]
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 1, 1, 0, 0, 0],
+        &[0, 1, 0, 0, 0, 1, 0, 0],
+        &[1, 0, 0, 0, 0, 1, 0, 0],
+        &[1, 0, 0, 0, 0, 1, 0, 0],
+        &[1, 0, 0, 0, 0, 1, 0, 0],
+        &[1, 0, 0, 0, 0, 1, 0, 0],
+        &[0, 1, 0, 0, 0, 1, 0, 0],
+        &[0, 0, 1, 1, 1, 0, 1, 1],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_CASE_B: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-    ]
+        &[0, 0, 0, 0],
+        &[1, 0, 0, 0],
+        &[1, 0, 0, 0],
+        &[1, 0, 0, 0],
+        &[1, 1, 1, 0],
+        &[1, 0, 0, 1],
+        &[1, 0, 0, 1],
+        &[1, 0, 0, 1],
+        &[1, 1, 1, 0],
+        &[0, 0, 0, 0],
+    ],
+    width: 4,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_CASE_C: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 1, 0, 0, 0],
-        &[0, 1, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 1, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 1, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 1, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-    ]
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 1, 1, 0],
+        &[0, 1, 0, 0, 0, 1],
+        &[1, 0, 0, 0, 0, 0],
+        &[1, 0, 0, 0, 0, 0],
+        &[1, 0, 0, 0, 0, 0],
+        &[1, 0, 0, 0, 0, 0],
+        &[0, 1, 0, 0, 0, 1],
+        &[0, 0, 1, 1, 1, 0],
+        &[0, 0, 0, 0, 0, 0],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_CASE_D: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-    ]
+        &[0, 0, 0, 0],
+        &[0, 0, 0, 1],
+        &[0, 0, 0, 1],
+        &[0, 0, 0, 1],
+        &[0, 1, 1, 1],
+        &[1, 0, 0, 1],
+        &[1, 0, 0, 1],
+        &[1, 0, 0, 1],
+        &[0, 1, 1, 1],
+        &[0, 0, 0, 0],
+    ],
+    width: 4,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_CASE_E: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 0, 1, 0, 0],
-        &[0, 1, 1, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 1, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-    ]
+        &[0, 0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0],
+        &[0, 1, 0, 0, 0, 0, 1],
+        &[0, 1, 0, 0, 0, 0, 1],
+        &[1, 1, 1, 1, 1, 1, 0],
+        &[1, 0, 0, 0, 0, 0, 0],
+        &[0, 1, 0, 0, 0, 0, 0],
+        &[0, 1, 0, 0, 0, 0, 1],
+        &[0, 0, 1, 0, 0, 1, 0],
+        &[0, 0, 0, 1, 1, 0, 0],
+    ],
+    width: 7,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_CASE_F: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-    ]
+        &[0, 0, 1, 1, 0],
+        &[0, 1, 0, 0, 1],
+        &[0, 1, 0, 0, 0],
+        &[0, 1, 0, 0, 0],
+        &[1, 1, 1, 1, 1],
+        &[0, 1, 0, 0, 0],
+        &[0, 1, 0, 0, 0],
+        &[0, 1, 0, 0, 0],
+        &[0, 1, 0, 0, 0],
+        &[0, 0, 0, 0, 0],
+    ],
+    width: 5,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_CASE_G: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 1, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 0, 0, 0, 0],
-    ]
+        &[0, 1, 1, 1, 0],
+        &[1, 0, 0, 0, 1],
+        &[1, 0, 0, 0, 1],
+        &[1, 0, 0, 0, 1],
+        &[0, 1, 1, 1, 0],
+        &[0, 0, 0, 0, 1],
+        &[0, 0, 0, 0, 1],
+        &[0, 0, 0, 0, 1],
+        &[1, 0, 0, 0, 1],
+        &[0, 1, 1, 1, 0],
+    ],
+    width: 5,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_CASE_H: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-    ]
+        &[0, 0, 0, 0],
+        &[1, 0, 0, 0],
+        &[1, 0, 0, 0],
+        &[1, 0, 0, 0],
+        &[1, 0, 0, 0],
+        &[1, 1, 1, 1],
+        &[1, 0, 0, 1],
+        &[1, 0, 0, 1],
+        &[1, 0, 0, 1],
+        &[0, 0, 0, 0],
+    ],
+    width: 4,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_CASE_I: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-    ]
+        &[0, 0],
+        &[1, 1],
+        &[1, 1],
+        &[0, 0],
+        &[0, 0],
+        &[1, 1],
+        &[1, 1],
+        &[1, 1],
+        &[1, 1],
+        &[1, 1],
+    ],
+    width: 2,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_CASE_J: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 1, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 0, 0, 0, 0, 0],
-    ]
+        &[0, 0, 0, 0],
+        &[0, 0, 1, 1],
+        &[0, 0, 1, 1],
+        &[0, 0, 0, 0],
+        &[0, 0, 0, 0],
+        &[0, 0, 1, 1],
+        &[0, 0, 1, 1],
+        &[1, 0, 1, 1],
+        &[1, 1, 1, 1],
+        &[0, 1, 1, 0],
+    ],
+    width: 4,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_CASE_K: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-    ]
+        &[0, 0, 0, 0],
+        &[1, 0, 0, 0],
+        &[1, 0, 0, 0],
+        &[1, 0, 0, 0],
+        &[1, 0, 0, 1],
+        &[1, 0, 1, 0],
+        &[1, 1, 0, 0],
+        &[1, 1, 0, 0],
+        &[1, 0, 1, 0],
+        &[1, 0, 0, 1],
+    ],
+    width: 4,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_CASE_L: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-    ]
+        &[0, 0, 0],
+        &[1, 0, 0],
+        &[1, 0, 0],
+        &[1, 0, 0],
+        &[1, 0, 0],
+        &[1, 0, 0],
+        &[1, 0, 0],
+        &[1, 0, 0],
+        &[1, 1, 1],
+        &[0, 0, 0],
+    ],
+    width: 3,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_CASE_M: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 1, 1, 0, 0, 0],
-        &[0, 1, 0, 0, 1, 0, 0, 1, 0, 0],
-        &[0, 1, 0, 0, 1, 0, 0, 1, 0, 0],
-        &[0, 1, 0, 0, 1, 0, 0, 1, 0, 0],
-        &[0, 1, 0, 0, 1, 0, 0, 1, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-    ]
+        &[0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0],
+        &[0, 1, 1, 0, 1, 1, 0],
+        &[1, 0, 0, 1, 0, 0, 1],
+        &[1, 0, 0, 1, 0, 0, 1],
+        &[1, 0, 0, 1, 0, 0, 1],
+        &[1, 0, 0, 1, 0, 0, 1],
+        &[0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 7,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_CASE_N: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-    ]
+        &[0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0],
+        &[1, 0, 1, 1, 0],
+        &[0, 1, 0, 0, 1],
+        &[0, 1, 0, 0, 1],
+        &[0, 1, 0, 0, 1],
+        &[0, 1, 0, 0, 1],
+        &[0, 0, 0, 0, 0],
+    ],
+    width: 5,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_CASE_O: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-    ]
+        &[0, 0, 0, 0],
+        &[0, 0, 0, 0],
+        &[0, 0, 0, 0],
+        &[0, 1, 1, 0],
+        &[1, 0, 0, 1],
+        &[1, 0, 0, 1],
+        &[1, 0, 0, 1],
+        &[1, 0, 0, 1],
+        &[0, 1, 1, 0],
+        &[0, 0, 0, 0],
+    ],
+    width: 4,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_CASE_P: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-    ]
+        &[0, 0, 0, 0],
+        &[0, 0, 0, 0],
+        &[0, 1, 1, 0],
+        &[1, 0, 0, 1],
+        &[1, 0, 0, 1],
+        &[1, 0, 0, 1],
+        &[1, 1, 1, 0],
+        &[1, 0, 0, 0],
+        &[1, 0, 0, 0],
+        &[0, 0, 0, 0],
+    ],
+    width: 4,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_CASE_Q: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-    ]
+        &[0, 0, 0, 0],
+        &[0, 0, 0, 0],
+        &[0, 1, 1, 0],
+        &[1, 0, 0, 1],
+        &[1, 0, 0, 1],
+        &[1, 0, 0, 1],
+        &[0, 1, 1, 1],
+        &[0, 0, 0, 1],
+        &[0, 0, 0, 1],
+        &[0, 0, 0, 0],
+    ],
+    width: 4,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_CASE_R: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 1, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-    ]
+        &[0, 0, 0, 0],
+        &[0, 0, 0, 0],
+        &[0, 0, 0, 0],
+        &[1, 0, 1, 1],
+        &[1, 1, 0, 0],
+        &[1, 0, 0, 0],
+        &[1, 0, 0, 0],
+        &[1, 0, 0, 0],
+        &[1, 0, 0, 0],
+        &[0, 0, 0, 0],
+    ],
+    width: 4,
+    baseline: 8,
+    kerning: &[(',', -1), ('.', -1)],
 };
 
 pub const FONT_CASE_S: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-    ]
+        &[0, 0, 0, 0],
+        &[0, 1, 1, 0],
+        &[1, 0, 0, 1],
+        &[1, 0, 0, 0],
+        &[0, 1, 0, 0],
+        &[0, 0, 1, 0],
+        &[0, 0, 0, 1],
+        &[1, 0, 0, 1],
+        &[0, 1, 1, 0],
+        &[0, 0, 0, 0],
+    ],
+    width: 4,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_CASE_T: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-    ]
+        &[0, 0, 0, 0],
+        &[0, 0, 0, 0],
+        &[0, 1, 0, 0],
+        &[1, 1, 1, 1],
+        &[0, 1, 0, 0],
+        &[0, 1, 0, 0],
+        &[0, 1, 0, 0],
+        &[0, 1, 0, 0],
+        &[0, 0, 1, 1],
+        &[0, 0, 0, 0],
+    ],
+    width: 4,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_CASE_U: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-    ]
+        &[0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0],
+        &[1, 0, 0, 1, 0],
+        &[1, 0, 0, 1, 0],
+        &[1, 0, 0, 1, 0],
+        &[1, 0, 0, 1, 0],
+        &[0, 1, 1, 1, 1],
+        &[0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0],
+    ],
+    width: 5,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_CASE_V: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 1, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-    ]
+        &[0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0],
+        &[1, 0, 0, 0, 1],
+        &[1, 0, 0, 0, 1],
+        &[1, 0, 0, 0, 1],
+        &[1, 1, 0, 1, 1],
+        &[0, 1, 0, 1, 0],
+        &[0, 0, 1, 0, 0],
+        &[0, 0, 0, 0, 0],
+    ],
+    width: 5,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_CASE_W: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 1, 0, 0, 1, 0, 0, 1, 0, 0],
-        &[0, 1, 0, 0, 1, 0, 0, 1, 0, 0],
-        &[0, 1, 0, 0, 1, 0, 0, 1, 0, 0],
-        &[0, 0, 1, 0, 1, 0, 1, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 1, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-    ]
+        &[0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0],
+        &[1, 0, 0, 1, 0, 0, 1],
+        &[1, 0, 0, 1, 0, 0, 1],
+        &[1, 0, 0, 1, 0, 0, 1],
+        &[0, 1, 0, 1, 0, 1, 0],
+        &[0, 1, 1, 0, 1, 1, 0],
+        &[0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 7,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_CASE_X: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 1, 1, 0, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 0, 1, 0, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 1, 0, 0, 0, 0],
-        &[0, 1, 1, 0, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-    ]
+        &[0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 1, 1],
+        &[0, 0, 1, 0, 1, 0, 0],
+        &[0, 0, 0, 1, 0, 0, 0],
+        &[0, 0, 0, 1, 0, 0, 0],
+        &[0, 0, 1, 0, 1, 0, 0],
+        &[1, 1, 0, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 7,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_CASE_Y: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 1, 1, 0, 0, 1, 0, 0, 0, 0],
-        &[0, 0, 1, 0, 0, 1, 0, 0, 0, 0],
-        &[0, 0, 1, 0, 0, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-    ]
+        &[0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 1],
+        &[0, 1, 0, 0, 1],
+        &[0, 1, 0, 0, 1],
+        &[0, 0, 1, 1, 1],
+        &[0, 0, 0, 0, 1],
+        &[0, 0, 0, 0, 1],
+        &[0, 0, 0, 0, 1],
+        &[0, 0, 0, 0, 0],
+    ],
+    width: 5,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_CASE_Z: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-    ]
+        &[0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1],
+        &[0, 0, 0, 0, 1],
+        &[0, 0, 0, 1, 0],
+        &[0, 0, 1, 0, 0],
+        &[0, 1, 0, 0, 0],
+        &[1, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1],
+        &[0, 0, 0, 0, 0],
+    ],
+    width: 5,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_1: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 1, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-    ]
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 1, 1, 1, 0, 0],
+        &[1, 1, 1, 1, 0, 0],
+        &[1, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 1, 1, 1, 1, 1],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_2: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 0, 0, 0, 1, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-    ]
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 1, 1, 1, 1, 0],
+        &[1, 1, 0, 0, 1, 1],
+        &[1, 0, 0, 0, 0, 1],
+        &[0, 0, 0, 0, 1, 1],
+        &[0, 0, 0, 1, 1, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 1, 1, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 1, 1, 1, 1, 1],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_3: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 1, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 1, 1, 0, 0, 0, 1, 1, 0, 0],
-        &[0, 1, 1, 0, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 1, 0, 0, 0],
-        &[0, 1, 1, 0, 0, 0, 1, 1, 0, 0],
-        &[0, 1, 1, 0, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 0, 0, 0],
-    ]
+        &[0, 0, 1, 1, 1, 0, 0],
+        &[0, 1, 1, 1, 1, 1, 0],
+        &[1, 1, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 1, 1],
+        &[0, 0, 0, 1, 1, 1, 0],
+        &[0, 0, 0, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 1, 0],
+        &[1, 1, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 1, 1],
+        &[0, 1, 1, 1, 1, 1, 0],
+    ],
+    width: 7,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_4: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 1, 1, 0, 0, 0],
-        &[0, 1, 1, 0, 0, 1, 1, 0, 0, 0],
-        &[1, 1, 0, 0, 0, 1, 1, 0, 0, 0],
-        &[1, 0, 0, 0, 0, 1, 1, 0, 0, 0],
-        &[1, 1, 1, 1, 1, 1, 1, 1, 1, 0],
-        &[1, 1, 1, 1, 1, 1, 1, 1, 1, 0],
-        &[0, 0, 0, 0, 0, 1, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 1, 1, 0, 0, 0],
-    ]
+        &[0, 0, 0, 0, 1, 1, 1, 0, 0],
+        &[0, 0, 0, 1, 1, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 1, 1, 0, 0],
+        &[0, 1, 1, 0, 0, 1, 1, 0, 0],
+        &[1, 1, 0, 0, 0, 1, 1, 0, 0],
+        &[1, 0, 0, 0, 0, 1, 1, 0, 0],
+        &[1, 1, 1, 1, 1, 1, 1, 1, 1],
+        &[1, 1, 1, 1, 1, 1, 1, 1, 1],
+        &[0, 0, 0, 0, 0, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 1, 1, 0, 0],
+    ],
+    width: 9,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_5: Font<'static> = Font {
     val: &[
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 0, 0, 0, 0],
-    ]
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 0, 0, 0, 1, 1],
+        &[1, 0, 0, 0, 0, 0],
+        &[1, 0, 1, 1, 0, 0],
+        &[1, 1, 1, 1, 1, 0],
+        &[0, 0, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 1, 1],
+        &[1, 0, 0, 0, 1, 1],
+        &[1, 1, 1, 1, 1, 0],
+        &[0, 1, 1, 1, 0, 0],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_6: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 1, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 1, 1, 0, 0],
-        &[0, 1, 1, 0, 0, 0, 0, 0, 0, 0],
-        &[0, 1, 1, 1, 1, 1, 0, 0, 0, 0],
-        &[0, 1, 1, 1, 0, 1, 1, 0, 0, 0],
-        &[0, 1, 1, 0, 0, 0, 1, 1, 0, 0],
-        &[0, 1, 1, 0, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 0, 1, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 0, 0, 0, 0],
-    ]
+        &[0, 0, 1, 1, 1, 0, 0],
+        &[0, 1, 1, 1, 1, 1, 0],
+        &[0, 1, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 0, 0],
+        &[1, 1, 1, 0, 1, 1, 0],
+        &[1, 1, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 1, 1],
+        &[0, 1, 1, 0, 1, 1, 0],
+        &[0, 0, 1, 1, 1, 0, 0],
+    ],
+    width: 7,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_7: Font<'static> = Font {
     val: &[
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 1, 1, 0, 0],
-    ]
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 1, 1, 1, 1, 1],
+        &[0, 0, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 1, 1],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_8: Font<'static> = Font {
     val: &[
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-    ]
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 0, 0, 0, 0, 1],
+        &[1, 0, 0, 0, 0, 1],
+        &[1, 0, 0, 0, 0, 1],
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 0, 0, 0, 0, 1],
+        &[1, 0, 0, 0, 0, 1],
+        &[1, 0, 0, 0, 0, 1],
+        &[1, 1, 1, 1, 1, 1],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_9: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 1, 1, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 0, 0, 0, 0, 1, 1, 0, 0],
-    ]
+        &[0, 1, 1, 1, 1, 1],
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 1, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 1, 1],
+        &[0, 1, 1, 1, 1, 1],
+        &[0, 1, 1, 1, 1, 1],
+        &[0, 0, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 1, 1],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
 };
 
 pub const FONT_0: Font<'static> = Font {
     val: &[
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 1, 0, 0, 0, 0, 1, 0, 0],
-        &[0, 0, 1, 1, 0, 0, 1, 1, 0, 0],
-        &[0, 0, 0, 1, 1, 1, 1, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
-    ]
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 1, 1, 1, 1, 0],
+        &[1, 1, 0, 0, 1, 1],
+        &[1, 0, 0, 0, 0, 1],
+        &[1, 0, 0, 0, 0, 1],
+        &[1, 0, 0, 0, 0, 1],
+        &[1, 0, 0, 0, 0, 1],
+        &[1, 1, 0, 0, 1, 1],
+        &[0, 1, 1, 1, 1, 0],
+        &[0, 0, 1, 1, 0, 0],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_EXCLAMATION: Font<'static> = Font {
+    val: &[
+        &[1, 1],
+        &[1, 1],
+        &[1, 1],
+        &[1, 1],
+        &[1, 1],
+        &[1, 1],
+        &[1, 1],
+        &[0, 0],
+        &[1, 1],
+        &[1, 1],
+    ],
+    width: 2,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_QUOTATION: Font<'static> = Font {
+    val: &[
+        &[1, 1, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_NUMBER: Font<'static> = Font {
+    val: &[
+        &[0, 0, 1, 0, 0, 1, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0, 0],
+        &[1, 1, 1, 1, 1, 1, 1, 1],
+        &[0, 0, 1, 0, 0, 1, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0, 0],
+        &[0, 1, 0, 0, 1, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1, 1, 1],
+        &[0, 1, 0, 0, 1, 0, 0, 0],
+        &[0, 1, 0, 0, 1, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_DOLLAR: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 1, 0, 0, 0],
+        &[0, 1, 1, 1, 1, 1, 1],
+        &[1, 1, 0, 1, 0, 0, 0],
+        &[1, 1, 0, 1, 0, 0, 0],
+        &[0, 1, 1, 1, 1, 1, 0],
+        &[0, 0, 0, 1, 0, 1, 1],
+        &[0, 0, 0, 1, 0, 1, 1],
+        &[1, 1, 1, 1, 1, 1, 0],
+        &[0, 0, 0, 1, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 7,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_PERCENT: Font<'static> = Font {
+    val: &[
+        &[0, 1, 1, 0, 0, 0, 0, 0, 1],
+        &[0, 1, 1, 0, 0, 0, 0, 1, 0],
+        &[0, 0, 0, 0, 0, 0, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 1, 0, 0, 0],
+        &[0, 0, 0, 0, 1, 0, 0, 0, 0],
+        &[0, 0, 0, 1, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 0, 0, 0, 0, 0, 0],
+        &[0, 1, 0, 0, 0, 0, 0, 1, 1],
+        &[1, 0, 0, 0, 0, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 9,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_AMPERSAND: Font<'static> = Font {
+    val: &[
+        &[0, 0, 1, 1, 0, 0, 0, 0],
+        &[0, 1, 0, 0, 1, 0, 0, 0],
+        &[0, 1, 0, 0, 1, 0, 0, 0],
+        &[0, 0, 1, 1, 0, 0, 0, 0],
+        &[0, 1, 1, 1, 0, 0, 0, 1],
+        &[1, 0, 0, 0, 1, 0, 1, 0],
+        &[1, 0, 0, 0, 0, 1, 0, 0],
+        &[1, 0, 0, 0, 1, 0, 1, 0],
+        &[0, 1, 1, 1, 0, 0, 0, 1],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_APOSTROPHE: Font<'static> = Font {
+    val: &[
+        &[1, 1],
+        &[1, 1],
+        &[1, 1],
+        &[0, 0],
+        &[0, 0],
+        &[0, 0],
+        &[0, 0],
+        &[0, 0],
+        &[0, 0],
+        &[0, 0],
+    ],
+    width: 2,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_LEFT_PARENTHESIS: Font<'static> = Font {
+    val: &[
+        &[0, 0, 1, 1],
+        &[0, 1, 1, 0],
+        &[1, 1, 0, 0],
+        &[1, 1, 0, 0],
+        &[1, 1, 0, 0],
+        &[1, 1, 0, 0],
+        &[1, 1, 0, 0],
+        &[0, 1, 1, 0],
+        &[0, 0, 1, 1],
+        &[0, 0, 0, 0],
+    ],
+    width: 4,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_RIGHT_PARENTHESIS: Font<'static> = Font {
+    val: &[
+        &[1, 1, 0, 0],
+        &[0, 1, 1, 0],
+        &[0, 0, 1, 1],
+        &[0, 0, 1, 1],
+        &[0, 0, 1, 1],
+        &[0, 0, 1, 1],
+        &[0, 0, 1, 1],
+        &[0, 1, 1, 0],
+        &[1, 1, 0, 0],
+        &[0, 0, 0, 0],
+    ],
+    width: 4,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_ASTERISK: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[1, 0, 0, 1, 1, 0, 0, 1],
+        &[0, 1, 1, 1, 1, 1, 1, 0],
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+        &[0, 1, 1, 1, 1, 1, 1, 0],
+        &[1, 0, 0, 1, 1, 0, 0, 1],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_PLUS: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1, 1, 1],
+        &[1, 1, 1, 1, 1, 1, 1, 1],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_COMMA: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0],
+        &[0, 0, 0],
+        &[0, 0, 0],
+        &[0, 0, 0],
+        &[0, 0, 0],
+        &[0, 0, 0],
+        &[0, 0, 0],
+        &[0, 1, 1],
+        &[0, 1, 1],
+        &[1, 1, 0],
+    ],
+    width: 3,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_HYPHEN: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 1, 1, 1, 1, 1],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_FULL_STOP: Font<'static> = Font {
+    val: &[
+        &[0, 0],
+        &[0, 0],
+        &[0, 0],
+        &[0, 0],
+        &[0, 0],
+        &[0, 0],
+        &[0, 0],
+        &[0, 0],
+        &[1, 1],
+        &[1, 1],
+    ],
+    width: 2,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_SOLIDUS: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 0, 1, 1, 0],
+        &[0, 0, 0, 0, 0, 1, 1, 0],
+        &[0, 0, 0, 0, 1, 1, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 1, 1, 0, 0, 0, 0],
+        &[0, 1, 1, 0, 0, 0, 0, 0],
+        &[0, 1, 1, 0, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_COLON: Font<'static> = Font {
+    val: &[
+        &[0, 0],
+        &[0, 0],
+        &[1, 1],
+        &[1, 1],
+        &[0, 0],
+        &[0, 0],
+        &[0, 0],
+        &[1, 1],
+        &[1, 1],
+        &[0, 0],
+    ],
+    width: 2,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_SEMICOLON: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0],
+        &[0, 0, 0],
+        &[0, 1, 1],
+        &[0, 1, 1],
+        &[0, 0, 0],
+        &[0, 0, 0],
+        &[0, 0, 0],
+        &[0, 1, 1],
+        &[0, 1, 1],
+        &[1, 1, 0],
+    ],
+    width: 3,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_LESS_THAN: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0, 0],
+        &[0, 0, 0, 1, 1],
+        &[0, 0, 1, 1, 0],
+        &[0, 1, 1, 0, 0],
+        &[1, 1, 0, 0, 0],
+        &[1, 1, 0, 0, 0],
+        &[0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0],
+        &[0, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 0],
+    ],
+    width: 5,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_EQUALS: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1, 1, 1],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1, 1, 1],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
 };
+
+pub const FONT_GREATER_THAN: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0],
+        &[0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0],
+        &[0, 0, 0, 1, 1],
+        &[0, 0, 0, 1, 1],
+        &[0, 0, 1, 1, 0],
+        &[0, 1, 1, 0, 0],
+        &[1, 1, 0, 0, 0],
+        &[0, 0, 0, 0, 0],
+    ],
+    width: 5,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_QUESTION: Font<'static> = Font {
+    val: &[
+        &[0, 1, 1, 1, 1, 0],
+        &[1, 1, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 1, 1],
+        &[0, 0, 0, 1, 1, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_AT: Font<'static> = Font {
+    val: &[
+        &[0, 0, 1, 1, 1, 1, 1, 0, 0],
+        &[0, 1, 0, 0, 0, 0, 0, 1, 0],
+        &[1, 0, 0, 1, 1, 1, 0, 0, 1],
+        &[1, 0, 1, 0, 0, 1, 0, 0, 1],
+        &[1, 0, 1, 0, 0, 1, 0, 0, 1],
+        &[1, 0, 0, 1, 1, 1, 0, 1, 0],
+        &[0, 1, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 1, 1, 1, 1, 1, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 9,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_LEFT_SQUARE_BRACKET: Font<'static> = Font {
+    val: &[
+        &[1, 1, 1, 1],
+        &[1, 1, 0, 0],
+        &[1, 1, 0, 0],
+        &[1, 1, 0, 0],
+        &[1, 1, 0, 0],
+        &[1, 1, 0, 0],
+        &[1, 1, 0, 0],
+        &[1, 1, 0, 0],
+        &[1, 1, 1, 1],
+        &[0, 0, 0, 0],
+    ],
+    width: 4,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_REVERSE_SOLIDUS: Font<'static> = Font {
+    val: &[
+        &[1, 1, 0, 0, 0, 0, 0, 0],
+        &[0, 1, 1, 0, 0, 0, 0, 0],
+        &[0, 1, 1, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 1, 0, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 0, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 1, 1, 0],
+        &[0, 0, 0, 0, 0, 1, 1, 0],
+        &[0, 0, 0, 0, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_RIGHT_SQUARE_BRACKET: Font<'static> = Font {
+    val: &[
+        &[1, 1, 1, 1],
+        &[0, 0, 1, 1],
+        &[0, 0, 1, 1],
+        &[0, 0, 1, 1],
+        &[0, 0, 1, 1],
+        &[0, 0, 1, 1],
+        &[0, 0, 1, 1],
+        &[0, 0, 1, 1],
+        &[1, 1, 1, 1],
+        &[0, 0, 0, 0],
+    ],
+    width: 4,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CIRCUMFLEX: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+        &[0, 1, 1, 0, 0, 1, 1, 0],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_LOW_LINE: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1, 1, 1],
+        &[1, 1, 1, 1, 1, 1, 1, 1],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_GRAVE: Font<'static> = Font {
+    val: &[
+        &[1, 1, 0, 0],
+        &[0, 1, 1, 0],
+        &[0, 0, 1, 1],
+        &[0, 0, 0, 0],
+        &[0, 0, 0, 0],
+        &[0, 0, 0, 0],
+        &[0, 0, 0, 0],
+        &[0, 0, 0, 0],
+        &[0, 0, 0, 0],
+        &[0, 0, 0, 0],
+    ],
+    width: 4,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_LEFT_CURLY_BRACKET: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 1, 1],
+        &[0, 0, 1, 1, 0],
+        &[0, 0, 1, 1, 0],
+        &[0, 0, 1, 1, 0],
+        &[1, 1, 0, 0, 0],
+        &[0, 0, 1, 1, 0],
+        &[0, 0, 1, 1, 0],
+        &[0, 0, 1, 1, 0],
+        &[0, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 0],
+    ],
+    width: 5,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_VERTICAL_LINE: Font<'static> = Font {
+    val: &[
+        &[1, 1],
+        &[1, 1],
+        &[1, 1],
+        &[1, 1],
+        &[1, 1],
+        &[1, 1],
+        &[1, 1],
+        &[1, 1],
+        &[1, 1],
+        &[1, 1],
+    ],
+    width: 2,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_RIGHT_CURLY_BRACKET: Font<'static> = Font {
+    val: &[
+        &[1, 1, 0, 0, 0],
+        &[0, 1, 1, 0, 0],
+        &[0, 1, 1, 0, 0],
+        &[0, 1, 1, 0, 0],
+        &[0, 0, 0, 1, 1],
+        &[0, 1, 1, 0, 0],
+        &[0, 1, 1, 0, 0],
+        &[0, 1, 1, 0, 0],
+        &[1, 1, 0, 0, 0],
+        &[0, 0, 0, 0, 0],
+    ],
+    width: 5,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_TILDE: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 1, 1, 1, 0, 0, 0, 1],
+        &[1, 0, 0, 0, 1, 0, 0, 1],
+        &[1, 0, 0, 0, 1, 1, 1, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_INVERTED_EXCLAMATION: Font<'static> = Font {
+    val: &[
+        &[1, 1],
+        &[1, 1],
+        &[0, 0],
+        &[1, 1],
+        &[1, 1],
+        &[1, 1],
+        &[1, 1],
+        &[1, 1],
+        &[1, 1],
+        &[1, 1],
+    ],
+    width: 2,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CENT: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0, 1, 0, 0],
+        &[0, 0, 1, 1, 1, 1, 0],
+        &[0, 1, 1, 0, 1, 0, 1],
+        &[1, 1, 0, 0, 1, 0, 0],
+        &[1, 1, 0, 0, 1, 0, 0],
+        &[1, 1, 0, 0, 1, 0, 0],
+        &[0, 1, 1, 0, 1, 0, 1],
+        &[0, 0, 1, 1, 1, 1, 0],
+        &[0, 0, 0, 0, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 7,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_POUND: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 1, 1, 1, 0],
+        &[0, 0, 1, 1, 0, 0, 1],
+        &[0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 1, 1, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1, 0],
+        &[0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 1, 1, 0, 0, 0],
+        &[0, 1, 1, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1, 1],
+        &[0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 7,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CURRENCY: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[1, 0, 0, 0, 0, 0, 0, 1],
+        &[0, 1, 1, 1, 1, 1, 1, 0],
+        &[0, 1, 0, 0, 0, 0, 1, 0],
+        &[0, 1, 0, 0, 0, 0, 1, 0],
+        &[0, 1, 0, 0, 0, 0, 1, 0],
+        &[0, 1, 1, 1, 1, 1, 1, 0],
+        &[1, 0, 0, 0, 0, 0, 0, 1],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_YEN: Font<'static> = Font {
+    val: &[
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[0, 1, 1, 0, 0, 1, 1, 0],
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 1, 1, 1, 1, 1, 1, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 1, 1, 1, 1, 1, 1, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_BROKEN_BAR: Font<'static> = Font {
+    val: &[
+        &[1, 1],
+        &[1, 1],
+        &[1, 1],
+        &[1, 1],
+        &[0, 0],
+        &[0, 0],
+        &[1, 1],
+        &[1, 1],
+        &[1, 1],
+        &[1, 1],
+    ],
+    width: 2,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_SECTION: Font<'static> = Font {
+    val: &[
+        &[0, 1, 1, 1, 1, 0],
+        &[1, 1, 0, 0, 0, 0],
+        &[0, 1, 1, 1, 0, 0],
+        &[1, 1, 0, 0, 1, 0],
+        &[1, 1, 0, 0, 1, 1],
+        &[0, 1, 0, 0, 1, 1],
+        &[0, 0, 1, 1, 1, 0],
+        &[0, 0, 0, 0, 1, 1],
+        &[0, 1, 1, 1, 1, 0],
+        &[0, 0, 0, 0, 0, 0],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_DIAERESIS: Font<'static> = Font {
+    val: &[
+        &[1, 1, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_COPYRIGHT: Font<'static> = Font {
+    val: &[
+        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
+        &[0, 1, 0, 0, 0, 0, 0, 0, 1, 0],
+        &[1, 0, 0, 1, 1, 1, 1, 0, 0, 1],
+        &[1, 0, 1, 0, 0, 0, 0, 0, 0, 1],
+        &[1, 0, 1, 0, 0, 0, 0, 0, 0, 1],
+        &[1, 0, 1, 0, 0, 0, 0, 0, 0, 1],
+        &[1, 0, 0, 1, 1, 1, 1, 0, 0, 1],
+        &[0, 1, 0, 0, 0, 0, 0, 0, 1, 0],
+        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 10,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_FEMININE_ORDINAL: Font<'static> = Font {
+    val: &[
+        &[0, 1, 1, 1, 0],
+        &[0, 0, 0, 0, 1],
+        &[0, 1, 1, 1, 1],
+        &[1, 0, 0, 0, 1],
+        &[0, 1, 1, 1, 1],
+        &[0, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1],
+        &[0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0],
+    ],
+    width: 5,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_LEFT_GUILLEMET: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 0, 0, 1],
+        &[0, 1, 0, 0, 1, 0],
+        &[1, 0, 0, 1, 0, 0],
+        &[0, 1, 0, 0, 1, 0],
+        &[0, 0, 1, 0, 0, 1],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_NOT: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1, 1, 1],
+        &[0, 0, 0, 0, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_REGISTERED: Font<'static> = Font {
+    val: &[
+        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
+        &[0, 1, 0, 0, 0, 0, 0, 0, 1, 0],
+        &[1, 0, 1, 1, 1, 1, 0, 0, 0, 1],
+        &[1, 0, 1, 0, 0, 0, 1, 0, 0, 1],
+        &[1, 0, 1, 1, 1, 1, 0, 0, 0, 1],
+        &[1, 0, 1, 0, 0, 1, 0, 0, 0, 1],
+        &[1, 0, 1, 0, 0, 0, 1, 0, 0, 1],
+        &[0, 1, 0, 0, 0, 0, 0, 0, 1, 0],
+        &[0, 0, 1, 1, 1, 1, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 10,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_MACRON: Font<'static> = Font {
+    val: &[
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 1, 1, 1, 1, 1],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_DEGREE: Font<'static> = Font {
+    val: &[
+        &[0, 1, 1, 1, 1, 0],
+        &[1, 1, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 1, 1],
+        &[0, 1, 1, 1, 1, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_PLUS_MINUS: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1, 1, 1],
+        &[1, 1, 1, 1, 1, 1, 1, 1],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1, 1, 1],
+        &[1, 1, 1, 1, 1, 1, 1, 1],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_SUPERSCRIPT_TWO: Font<'static> = Font {
+    val: &[
+        &[1, 1, 1, 1, 0],
+        &[0, 0, 0, 1, 1],
+        &[0, 0, 1, 1, 0],
+        &[1, 1, 0, 0, 0],
+        &[1, 1, 1, 1, 1],
+        &[0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0],
+    ],
+    width: 5,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_SUPERSCRIPT_THREE: Font<'static> = Font {
+    val: &[
+        &[1, 1, 1, 1, 0],
+        &[0, 0, 0, 1, 1],
+        &[0, 1, 1, 1, 0],
+        &[0, 0, 0, 1, 1],
+        &[1, 1, 1, 1, 0],
+        &[0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0],
+    ],
+    width: 5,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_ACUTE: Font<'static> = Font {
+    val: &[
+        &[0, 0, 1, 1],
+        &[0, 1, 1, 0],
+        &[1, 1, 0, 0],
+        &[0, 0, 0, 0],
+        &[0, 0, 0, 0],
+        &[0, 0, 0, 0],
+        &[0, 0, 0, 0],
+        &[0, 0, 0, 0],
+        &[0, 0, 0, 0],
+        &[0, 0, 0, 0],
+    ],
+    width: 4,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_MICRO: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 1, 1, 0],
+        &[1, 1, 0, 0, 0, 1, 1, 0],
+        &[1, 1, 0, 0, 0, 1, 1, 0],
+        &[1, 1, 0, 0, 0, 1, 1, 0],
+        &[1, 1, 1, 0, 1, 1, 1, 0],
+        &[1, 1, 0, 1, 1, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_PILCROW: Font<'static> = Font {
+    val: &[
+        &[0, 1, 1, 1, 1, 1, 1, 1],
+        &[1, 1, 1, 1, 1, 0, 1, 0],
+        &[1, 1, 1, 1, 1, 0, 1, 0],
+        &[0, 1, 1, 1, 1, 0, 1, 0],
+        &[0, 0, 0, 0, 1, 0, 1, 0],
+        &[0, 0, 0, 0, 1, 0, 1, 0],
+        &[0, 0, 0, 0, 1, 0, 1, 0],
+        &[0, 0, 0, 0, 1, 0, 1, 0],
+        &[0, 0, 0, 0, 1, 0, 1, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_MIDDLE_DOT: Font<'static> = Font {
+    val: &[
+        &[0, 0],
+        &[0, 0],
+        &[0, 0],
+        &[0, 0],
+        &[1, 1],
+        &[1, 1],
+        &[0, 0],
+        &[0, 0],
+        &[0, 0],
+        &[0, 0],
+    ],
+    width: 2,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CEDILLA: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0],
+        &[0, 0, 0],
+        &[0, 0, 0],
+        &[0, 0, 0],
+        &[0, 0, 0],
+        &[0, 0, 0],
+        &[0, 0, 0],
+        &[0, 1, 0],
+        &[0, 0, 1],
+        &[1, 1, 0],
+    ],
+    width: 3,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_SUPERSCRIPT_ONE: Font<'static> = Font {
+    val: &[
+        &[0, 1, 1, 0],
+        &[1, 1, 1, 0],
+        &[0, 1, 1, 0],
+        &[0, 1, 1, 0],
+        &[1, 1, 1, 1],
+        &[0, 0, 0, 0],
+        &[0, 0, 0, 0],
+        &[0, 0, 0, 0],
+        &[0, 0, 0, 0],
+        &[0, 0, 0, 0],
+    ],
+    width: 4,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_MASCULINE_ORDINAL: Font<'static> = Font {
+    val: &[
+        &[0, 1, 1, 1, 0],
+        &[1, 0, 0, 0, 1],
+        &[1, 0, 0, 0, 1],
+        &[0, 1, 1, 1, 0],
+        &[0, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1],
+        &[0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0],
+    ],
+    width: 5,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_RIGHT_GUILLEMET: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[1, 0, 0, 1, 0, 0],
+        &[0, 1, 0, 0, 1, 0],
+        &[0, 0, 1, 0, 0, 1],
+        &[0, 1, 0, 0, 1, 0],
+        &[1, 0, 0, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_ONE_QUARTER: Font<'static> = Font {
+    val: &[
+        &[0, 1, 0, 0, 0, 0, 0, 1, 0],
+        &[1, 1, 0, 0, 0, 0, 1, 0, 0],
+        &[0, 1, 0, 0, 0, 1, 0, 0, 0],
+        &[0, 1, 0, 0, 1, 0, 0, 0, 0],
+        &[0, 0, 0, 1, 0, 0, 0, 1, 0],
+        &[0, 0, 1, 0, 0, 0, 1, 1, 0],
+        &[0, 1, 0, 0, 0, 1, 0, 1, 0],
+        &[1, 0, 0, 0, 1, 1, 1, 1, 1],
+        &[0, 0, 0, 0, 0, 0, 0, 1, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 9,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_ONE_HALF: Font<'static> = Font {
+    val: &[
+        &[0, 1, 0, 0, 0, 0, 0, 1, 0],
+        &[1, 1, 0, 0, 0, 0, 1, 0, 0],
+        &[0, 1, 0, 0, 0, 1, 0, 0, 0],
+        &[0, 1, 0, 0, 1, 0, 0, 0, 0],
+        &[0, 0, 0, 1, 0, 1, 1, 1, 0],
+        &[0, 0, 1, 0, 0, 0, 0, 0, 1],
+        &[0, 1, 0, 0, 0, 0, 0, 1, 0],
+        &[1, 0, 0, 0, 0, 0, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 1, 1, 1, 1],
+        &[0, 0, 0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 9,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_THREE_QUARTERS: Font<'static> = Font {
+    val: &[
+        &[1, 1, 0, 0, 0, 0, 0, 1, 0],
+        &[0, 0, 1, 0, 0, 0, 1, 0, 0],
+        &[0, 1, 0, 0, 0, 1, 0, 0, 0],
+        &[0, 0, 1, 0, 1, 0, 0, 0, 0],
+        &[1, 1, 0, 1, 0, 0, 0, 1, 0],
+        &[0, 0, 1, 0, 0, 0, 1, 1, 0],
+        &[0, 1, 0, 0, 0, 1, 0, 1, 0],
+        &[1, 0, 0, 0, 1, 1, 1, 1, 1],
+        &[0, 0, 0, 0, 0, 0, 0, 1, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 9,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_INVERTED_QUESTION: Font<'static> = Font {
+    val: &[
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 1, 1, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 1, 1],
+        &[0, 1, 1, 1, 1, 0],
+        &[0, 0, 0, 0, 0, 0],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_A_GRAVE: Font<'static> = Font {
+    val: &[
+        &[0, 0, 1, 1, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0, 0],
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+        &[0, 1, 1, 1, 1, 1, 1, 0],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 0, 0, 0, 0, 0, 0, 1],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_A_ACUTE: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0, 0],
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+        &[0, 1, 1, 1, 1, 1, 1, 0],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 0, 0, 0, 0, 0, 0, 1],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_A_CIRCUMFLEX: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0, 0],
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+        &[0, 1, 1, 1, 1, 1, 1, 0],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 0, 0, 0, 0, 0, 0, 1],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_A_TILDE: Font<'static> = Font {
+    val: &[
+        &[0, 0, 1, 1, 0, 0, 1, 0],
+        &[0, 1, 0, 0, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0, 0],
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+        &[0, 1, 1, 1, 1, 1, 1, 0],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 0, 0, 0, 0, 0, 0, 1],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_A_DIAERESIS: Font<'static> = Font {
+    val: &[
+        &[0, 1, 1, 0, 0, 1, 1, 0],
+        &[0, 1, 1, 0, 0, 1, 1, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0, 0],
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+        &[0, 1, 1, 1, 1, 1, 1, 0],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 0, 0, 0, 0, 0, 0, 1],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_A_RING: Font<'static> = Font {
+    val: &[
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0, 0],
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+        &[0, 1, 1, 1, 1, 1, 1, 0],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 0, 0, 0, 0, 0, 0, 1],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_AE: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 1, 1, 1, 1, 1, 1],
+        &[0, 0, 1, 1, 0, 1, 0, 0, 0],
+        &[0, 0, 1, 1, 0, 1, 0, 0, 0],
+        &[0, 1, 1, 0, 0, 1, 0, 0, 0],
+        &[0, 1, 1, 0, 0, 1, 1, 1, 1],
+        &[0, 1, 1, 1, 1, 1, 1, 0, 0],
+        &[0, 1, 1, 0, 0, 1, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 1, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 1, 1, 1, 1],
+        &[0, 0, 0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 9,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_C_CEDILLA: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 1, 1, 0, 0],
+        &[0, 1, 1, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1],
+        &[1, 1, 0, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0, 1],
+        &[0, 0, 1, 1, 1, 1, 0],
+        &[0, 0, 0, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 1, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 7,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_E_GRAVE: Font<'static> = Font {
+    val: &[
+        &[0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_E_ACUTE: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 1, 1, 0],
+        &[0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_E_CIRCUMFLEX: Font<'static> = Font {
+    val: &[
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 1, 0, 0, 1, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_E_DIAERESIS: Font<'static> = Font {
+    val: &[
+        &[1, 1, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_I_GRAVE: Font<'static> = Font {
+    val: &[
+        &[0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 1, 1, 1, 1, 1],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_I_ACUTE: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 1, 1, 0],
+        &[0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 1, 1, 1, 1, 1],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_I_CIRCUMFLEX: Font<'static> = Font {
+    val: &[
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 1, 0, 0, 1, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 1, 1, 1, 1, 1],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_I_DIAERESIS: Font<'static> = Font {
+    val: &[
+        &[1, 1, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[1, 1, 1, 1, 1, 1],
+        &[1, 1, 1, 1, 1, 1],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_ETH: Font<'static> = Font {
+    val: &[
+        &[0, 1, 1, 1, 1, 1, 0, 0],
+        &[0, 1, 1, 0, 0, 1, 1, 0],
+        &[0, 1, 1, 0, 0, 0, 1, 1],
+        &[0, 1, 1, 0, 0, 0, 1, 1],
+        &[1, 1, 1, 1, 0, 0, 1, 1],
+        &[0, 1, 1, 0, 0, 0, 1, 1],
+        &[0, 1, 1, 0, 0, 0, 1, 1],
+        &[0, 1, 1, 0, 0, 1, 1, 0],
+        &[0, 1, 1, 1, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_N_TILDE: Font<'static> = Font {
+    val: &[
+        &[0, 0, 1, 1, 0, 0, 1, 0],
+        &[0, 1, 0, 0, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[1, 1, 1, 0, 0, 0, 1, 1],
+        &[1, 1, 1, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 1, 0, 0, 1, 1],
+        &[1, 1, 0, 1, 1, 0, 1, 1],
+        &[1, 1, 0, 0, 1, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 1, 1, 1],
+        &[1, 1, 0, 0, 0, 1, 1, 1],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_O_GRAVE: Font<'static> = Font {
+    val: &[
+        &[0, 0, 1, 1, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+        &[0, 1, 0, 0, 0, 0, 1, 0],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[0, 1, 1, 0, 0, 1, 1, 0],
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_O_ACUTE: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+        &[0, 1, 0, 0, 0, 0, 1, 0],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[0, 1, 1, 0, 0, 1, 1, 0],
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_O_CIRCUMFLEX: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+        &[0, 1, 0, 0, 0, 0, 1, 0],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[0, 1, 1, 0, 0, 1, 1, 0],
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_O_TILDE: Font<'static> = Font {
+    val: &[
+        &[0, 0, 1, 1, 0, 0, 1, 0],
+        &[0, 1, 0, 0, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+        &[0, 1, 0, 0, 0, 0, 1, 0],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[0, 1, 1, 0, 0, 1, 1, 0],
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_O_DIAERESIS: Font<'static> = Font {
+    val: &[
+        &[0, 1, 1, 0, 0, 1, 1, 0],
+        &[0, 1, 1, 0, 0, 1, 1, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+        &[0, 1, 0, 0, 0, 0, 1, 0],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[0, 1, 1, 0, 0, 1, 1, 0],
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_MULTIPLICATION: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[0, 1, 1, 0, 0, 1, 1, 0],
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+        &[0, 1, 1, 0, 0, 1, 1, 0],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_O_STROKE: Font<'static> = Font {
+    val: &[
+        &[0, 0, 1, 1, 1, 1, 0, 1],
+        &[0, 1, 1, 0, 0, 1, 1, 0],
+        &[1, 1, 0, 0, 0, 1, 1, 1],
+        &[1, 1, 0, 0, 1, 0, 1, 1],
+        &[1, 1, 0, 1, 0, 0, 1, 1],
+        &[1, 1, 0, 1, 0, 0, 1, 1],
+        &[1, 1, 1, 0, 0, 0, 1, 1],
+        &[0, 1, 1, 0, 0, 1, 1, 0],
+        &[1, 0, 1, 1, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_U_GRAVE: Font<'static> = Font {
+    val: &[
+        &[0, 0, 1, 1, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[0, 1, 1, 1, 1, 1, 1, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_U_ACUTE: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[0, 1, 1, 1, 1, 1, 1, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_U_CIRCUMFLEX: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[0, 1, 1, 1, 1, 1, 1, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_U_DIAERESIS: Font<'static> = Font {
+    val: &[
+        &[0, 1, 1, 0, 0, 1, 1, 0],
+        &[0, 1, 1, 0, 0, 1, 1, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 0, 1, 1],
+        &[0, 1, 1, 1, 1, 1, 1, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_Y_ACUTE: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[1, 0, 0, 0, 0, 0, 0, 1],
+        &[0, 1, 0, 0, 0, 0, 1, 0],
+        &[0, 1, 1, 0, 0, 1, 1, 0],
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_THORN: Font<'static> = Font {
+    val: &[
+        &[1, 1, 0, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1, 0],
+        &[1, 1, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 0, 1, 1],
+        &[1, 1, 1, 1, 1, 1, 0],
+        &[1, 1, 0, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 7,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_SHARP_S: Font<'static> = Font {
+    val: &[
+        &[0, 1, 1, 1, 0, 0],
+        &[1, 0, 0, 0, 1, 0],
+        &[1, 0, 0, 0, 1, 0],
+        &[1, 0, 0, 1, 0, 0],
+        &[1, 0, 1, 1, 0, 0],
+        &[1, 0, 0, 0, 1, 0],
+        &[1, 0, 0, 0, 0, 1],
+        &[1, 0, 0, 0, 0, 1],
+        &[1, 0, 1, 1, 1, 0],
+        &[0, 0, 0, 0, 0, 0],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_A_GRAVE: Font<'static> = Font {
+    val: &[
+        &[0, 0, 1, 1, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 1, 1, 0, 0, 0],
+        &[0, 1, 0, 0, 0, 1, 0, 0],
+        &[1, 0, 0, 0, 0, 1, 0, 0],
+        &[1, 0, 0, 0, 0, 1, 0, 0],
+        &[1, 0, 0, 0, 0, 1, 0, 0],
+        &[0, 1, 0, 0, 0, 1, 0, 0],
+        &[0, 0, 1, 1, 1, 0, 1, 1],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_A_ACUTE: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 1, 1, 0, 0, 0],
+        &[0, 1, 0, 0, 0, 1, 0, 0],
+        &[1, 0, 0, 0, 0, 1, 0, 0],
+        &[1, 0, 0, 0, 0, 1, 0, 0],
+        &[1, 0, 0, 0, 0, 1, 0, 0],
+        &[0, 1, 0, 0, 0, 1, 0, 0],
+        &[0, 0, 1, 1, 1, 0, 1, 1],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_A_CIRCUMFLEX: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 1, 1, 0, 0, 0],
+        &[0, 1, 0, 0, 0, 1, 0, 0],
+        &[1, 0, 0, 0, 0, 1, 0, 0],
+        &[1, 0, 0, 0, 0, 1, 0, 0],
+        &[1, 0, 0, 0, 0, 1, 0, 0],
+        &[0, 1, 0, 0, 0, 1, 0, 0],
+        &[0, 0, 1, 1, 1, 0, 1, 1],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_A_TILDE: Font<'static> = Font {
+    val: &[
+        &[0, 0, 1, 1, 0, 0, 1, 0],
+        &[0, 1, 0, 0, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 1, 1, 0, 0, 0],
+        &[0, 1, 0, 0, 0, 1, 0, 0],
+        &[1, 0, 0, 0, 0, 1, 0, 0],
+        &[1, 0, 0, 0, 0, 1, 0, 0],
+        &[1, 0, 0, 0, 0, 1, 0, 0],
+        &[0, 1, 0, 0, 0, 1, 0, 0],
+        &[0, 0, 1, 1, 1, 0, 1, 1],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_A_DIAERESIS: Font<'static> = Font {
+    val: &[
+        &[0, 1, 1, 0, 0, 1, 1, 0],
+        &[0, 1, 1, 0, 0, 1, 1, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 1, 1, 0, 0, 0],
+        &[0, 1, 0, 0, 0, 1, 0, 0],
+        &[1, 0, 0, 0, 0, 1, 0, 0],
+        &[1, 0, 0, 0, 0, 1, 0, 0],
+        &[1, 0, 0, 0, 0, 1, 0, 0],
+        &[0, 1, 0, 0, 0, 1, 0, 0],
+        &[0, 0, 1, 1, 1, 0, 1, 1],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_A_RING: Font<'static> = Font {
+    val: &[
+        &[0, 0, 1, 1, 1, 1, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 1, 1, 0, 0, 0],
+        &[0, 1, 0, 0, 0, 1, 0, 0],
+        &[1, 0, 0, 0, 0, 1, 0, 0],
+        &[1, 0, 0, 0, 0, 1, 0, 0],
+        &[1, 0, 0, 0, 0, 1, 0, 0],
+        &[0, 1, 0, 0, 0, 1, 0, 0],
+        &[0, 0, 1, 1, 1, 0, 1, 1],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_AE: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 1, 1, 1, 0, 1, 1, 0],
+        &[0, 0, 0, 0, 1, 0, 0, 1],
+        &[0, 1, 1, 1, 1, 0, 0, 1],
+        &[1, 0, 0, 0, 1, 1, 1, 1],
+        &[1, 0, 0, 0, 1, 0, 0, 0],
+        &[0, 1, 1, 1, 0, 1, 1, 1],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_C_CEDILLA: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 1, 1, 0],
+        &[0, 1, 0, 0, 0, 1],
+        &[1, 0, 0, 0, 0, 0],
+        &[1, 0, 0, 0, 0, 0],
+        &[0, 1, 0, 0, 0, 1],
+        &[0, 0, 1, 1, 1, 0],
+        &[0, 0, 0, 0, 1, 0],
+        &[0, 0, 0, 1, 1, 0],
+        &[0, 0, 0, 0, 0, 0],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_E_GRAVE: Font<'static> = Font {
+    val: &[
+        &[0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 0, 1, 1, 0],
+        &[0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0],
+        &[0, 1, 0, 0, 0, 0, 1],
+        &[0, 1, 0, 0, 0, 0, 1],
+        &[1, 0, 0, 0, 0, 0, 0],
+        &[0, 1, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0],
+        &[0, 0, 0, 1, 1, 0, 0],
+    ],
+    width: 7,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_E_ACUTE: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0, 1, 1, 0],
+        &[0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0],
+        &[0, 1, 0, 0, 0, 0, 1],
+        &[0, 1, 0, 0, 0, 0, 1],
+        &[1, 0, 0, 0, 0, 0, 0],
+        &[0, 1, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0],
+        &[0, 0, 0, 1, 1, 0, 0],
+    ],
+    width: 7,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_E_CIRCUMFLEX: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0],
+        &[0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0],
+        &[0, 1, 0, 0, 0, 0, 1],
+        &[0, 1, 0, 0, 0, 0, 1],
+        &[1, 0, 0, 0, 0, 0, 0],
+        &[0, 1, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0],
+        &[0, 0, 0, 1, 1, 0, 0],
+    ],
+    width: 7,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_E_DIAERESIS: Font<'static> = Font {
+    val: &[
+        &[0, 1, 1, 0, 0, 1, 1],
+        &[0, 1, 1, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0],
+        &[0, 1, 0, 0, 0, 0, 1],
+        &[0, 1, 0, 0, 0, 0, 1],
+        &[1, 0, 0, 0, 0, 0, 0],
+        &[0, 1, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0],
+        &[0, 0, 0, 1, 1, 0, 0],
+    ],
+    width: 7,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_I_GRAVE: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0],
+        &[0, 0, 0, 0],
+        &[1, 1, 0, 0],
+        &[0, 0, 1, 1],
+        &[0, 0, 0, 0],
+        &[0, 1, 1, 0],
+        &[0, 1, 1, 0],
+        &[0, 1, 1, 0],
+        &[0, 1, 1, 0],
+        &[0, 1, 1, 0],
+    ],
+    width: 4,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_I_ACUTE: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0],
+        &[0, 0, 0, 0],
+        &[0, 0, 1, 1],
+        &[1, 1, 0, 0],
+        &[0, 0, 0, 0],
+        &[0, 1, 1, 0],
+        &[0, 1, 1, 0],
+        &[0, 1, 1, 0],
+        &[0, 1, 1, 0],
+        &[0, 1, 1, 0],
+    ],
+    width: 4,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_I_CIRCUMFLEX: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0],
+        &[0, 0, 0, 0],
+        &[0, 1, 1, 0],
+        &[1, 0, 0, 1],
+        &[0, 0, 0, 0],
+        &[0, 1, 1, 0],
+        &[0, 1, 1, 0],
+        &[0, 1, 1, 0],
+        &[0, 1, 1, 0],
+        &[0, 1, 1, 0],
+    ],
+    width: 4,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_I_DIAERESIS: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_ETH: Font<'static> = Font {
+    val: &[
+        &[1, 1, 0, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 1, 0, 1, 0, 0],
+        &[0, 0, 0, 0, 1, 0],
+        &[0, 1, 1, 1, 1, 0],
+        &[1, 0, 0, 0, 0, 1],
+        &[1, 0, 0, 0, 0, 1],
+        &[1, 0, 0, 0, 0, 1],
+        &[0, 1, 1, 1, 1, 0],
+        &[0, 0, 0, 0, 0, 0],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_N_TILDE: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 1, 1, 0, 0, 1],
+        &[1, 0, 0, 1, 1, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[1, 0, 1, 1, 0, 0],
+        &[0, 1, 0, 0, 1, 0],
+        &[0, 1, 0, 0, 1, 0],
+        &[0, 1, 0, 0, 1, 0],
+        &[0, 1, 0, 0, 1, 0],
+        &[0, 0, 0, 0, 0, 0],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_O_GRAVE: Font<'static> = Font {
+    val: &[
+        &[1, 1, 0, 0],
+        &[0, 0, 1, 1],
+        &[0, 0, 0, 0],
+        &[0, 1, 1, 0],
+        &[1, 0, 0, 1],
+        &[1, 0, 0, 1],
+        &[1, 0, 0, 1],
+        &[1, 0, 0, 1],
+        &[0, 1, 1, 0],
+        &[0, 0, 0, 0],
+    ],
+    width: 4,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_O_ACUTE: Font<'static> = Font {
+    val: &[
+        &[0, 0, 1, 1],
+        &[1, 1, 0, 0],
+        &[0, 0, 0, 0],
+        &[0, 1, 1, 0],
+        &[1, 0, 0, 1],
+        &[1, 0, 0, 1],
+        &[1, 0, 0, 1],
+        &[1, 0, 0, 1],
+        &[0, 1, 1, 0],
+        &[0, 0, 0, 0],
+    ],
+    width: 4,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_O_CIRCUMFLEX: Font<'static> = Font {
+    val: &[
+        &[0, 1, 1, 0],
+        &[1, 0, 0, 1],
+        &[0, 0, 0, 0],
+        &[0, 1, 1, 0],
+        &[1, 0, 0, 1],
+        &[1, 0, 0, 1],
+        &[1, 0, 0, 1],
+        &[1, 0, 0, 1],
+        &[0, 1, 1, 0],
+        &[0, 0, 0, 0],
+    ],
+    width: 4,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_O_TILDE: Font<'static> = Font {
+    val: &[
+        &[0, 1, 1, 0, 0, 1],
+        &[1, 0, 0, 1, 1, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 1, 0, 0, 1, 0],
+        &[0, 1, 0, 0, 1, 0],
+        &[0, 1, 0, 0, 1, 0],
+        &[0, 1, 0, 0, 1, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_O_DIAERESIS: Font<'static> = Font {
+    val: &[
+        &[1, 1, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 1, 0, 0, 1, 0],
+        &[0, 1, 0, 0, 1, 0],
+        &[0, 1, 0, 0, 1, 0],
+        &[0, 1, 0, 0, 1, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_DIVISION: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[1, 1, 1, 1, 1, 1, 1, 1],
+        &[1, 1, 1, 1, 1, 1, 1, 1],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0],
+    ],
+    width: 8,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_O_STROKE: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 1],
+        &[0, 1, 1, 1, 1, 0],
+        &[1, 0, 0, 1, 0, 1],
+        &[1, 0, 0, 1, 0, 1],
+        &[1, 0, 1, 0, 0, 1],
+        &[0, 1, 1, 1, 1, 0],
+        &[1, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_U_GRAVE: Font<'static> = Font {
+    val: &[
+        &[1, 1, 0, 0, 0],
+        &[0, 0, 1, 1, 0],
+        &[0, 0, 0, 0, 0],
+        &[1, 0, 0, 1, 0],
+        &[1, 0, 0, 1, 0],
+        &[1, 0, 0, 1, 0],
+        &[1, 0, 0, 1, 0],
+        &[0, 1, 1, 1, 1],
+        &[0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0],
+    ],
+    width: 5,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_U_ACUTE: Font<'static> = Font {
+    val: &[
+        &[0, 0, 1, 1, 0],
+        &[1, 1, 0, 0, 0],
+        &[0, 0, 0, 0, 0],
+        &[1, 0, 0, 1, 0],
+        &[1, 0, 0, 1, 0],
+        &[1, 0, 0, 1, 0],
+        &[1, 0, 0, 1, 0],
+        &[0, 1, 1, 1, 1],
+        &[0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0],
+    ],
+    width: 5,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_U_CIRCUMFLEX: Font<'static> = Font {
+    val: &[
+        &[0, 1, 1, 0, 0],
+        &[1, 0, 0, 1, 0],
+        &[0, 0, 0, 0, 0],
+        &[1, 0, 0, 1, 0],
+        &[1, 0, 0, 1, 0],
+        &[1, 0, 0, 1, 0],
+        &[1, 0, 0, 1, 0],
+        &[0, 1, 1, 1, 1],
+        &[0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0],
+    ],
+    width: 5,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_U_DIAERESIS: Font<'static> = Font {
+    val: &[
+        &[1, 1, 0, 0, 1, 1],
+        &[1, 1, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 1, 0, 0, 1, 0],
+        &[0, 1, 0, 0, 1, 0],
+        &[0, 1, 0, 0, 1, 0],
+        &[0, 1, 0, 0, 1, 0],
+        &[0, 0, 1, 1, 1, 1],
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_Y_ACUTE: Font<'static> = Font {
+    val: &[
+        &[0, 0, 0, 0, 1, 1],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 1, 0],
+        &[0, 1, 0, 0, 1, 0],
+        &[0, 1, 0, 0, 1, 0],
+        &[0, 0, 1, 1, 1, 0],
+        &[0, 0, 0, 0, 1, 0],
+        &[0, 0, 0, 0, 1, 0],
+        &[0, 0, 0, 0, 1, 0],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_THORN: Font<'static> = Font {
+    val: &[
+        &[1, 0, 0, 0, 0, 0],
+        &[1, 0, 0, 0, 0, 0],
+        &[1, 0, 1, 1, 1, 0],
+        &[1, 1, 0, 0, 0, 1],
+        &[1, 0, 0, 0, 0, 1],
+        &[1, 0, 0, 0, 0, 1],
+        &[1, 1, 0, 0, 0, 1],
+        &[1, 0, 1, 1, 1, 0],
+        &[1, 0, 0, 0, 0, 0],
+        &[1, 0, 0, 0, 0, 0],
+    ],
+    width: 6,
+    baseline: 8,
+    kerning: &[],
+};
+
+pub const FONT_CASE_Y_DIAERESIS: Font<'static> = Font {
+    val: &[
+        &[0, 1, 1, 0, 0, 1, 1],
+        &[0, 1, 1, 0, 0, 1, 1],
+        &[0, 0, 0, 0, 0, 0, 0],
+        &[1, 1, 0, 0, 1, 0, 0],
+        &[0, 1, 0, 0, 1, 0, 0],
+        &[0, 1, 0, 0, 1, 0, 0],
+        &[0, 0, 1, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 1, 0, 0],
+        &[0, 0, 0, 0, 1, 0, 0],
+        &[0, 0, 0, 0, 1, 0, 0],
+    ],
+    width: 7,
+    baseline: 8,
+    kerning: &[],
+};
+
+// Glyphs of the printable ASCII characters, from ' ' to '~'.
+const ASCII: &[Font<'static>] = &[
+    FONT_EMPTY, FONT_EXCLAMATION, FONT_QUOTATION, FONT_NUMBER, FONT_DOLLAR,
+    FONT_PERCENT, FONT_AMPERSAND, FONT_APOSTROPHE, FONT_LEFT_PARENTHESIS,
+    FONT_RIGHT_PARENTHESIS, FONT_ASTERISK, FONT_PLUS, FONT_COMMA, FONT_HYPHEN,
+    FONT_FULL_STOP, FONT_SOLIDUS, FONT_0, FONT_1, FONT_2, FONT_3, FONT_4, FONT_5,
+    FONT_6, FONT_7, FONT_8, FONT_9, FONT_COLON, FONT_SEMICOLON, FONT_LESS_THAN,
+    FONT_EQUALS, FONT_GREATER_THAN, FONT_QUESTION, FONT_AT, FONT_A, FONT_B, FONT_C,
+    FONT_D, FONT_E, FONT_F, FONT_G, FONT_H, FONT_I, FONT_J, FONT_K, FONT_L, FONT_M,
+    FONT_N, FONT_O, FONT_P, FONT_Q, FONT_R, FONT_S, FONT_T, FONT_U, FONT_V, FONT_W,
+    FONT_X, FONT_Y, FONT_Z, FONT_LEFT_SQUARE_BRACKET, FONT_REVERSE_SOLIDUS,
+    FONT_RIGHT_SQUARE_BRACKET, FONT_CIRCUMFLEX, FONT_LOW_LINE, FONT_GRAVE, FONT_CASE_A,
+    FONT_CASE_B, FONT_CASE_C, FONT_CASE_D, FONT_CASE_E, FONT_CASE_F, FONT_CASE_G,
+    FONT_CASE_H, FONT_CASE_I, FONT_CASE_J, FONT_CASE_K, FONT_CASE_L, FONT_CASE_M,
+    FONT_CASE_N, FONT_CASE_O, FONT_CASE_P, FONT_CASE_Q, FONT_CASE_R, FONT_CASE_S,
+    FONT_CASE_T, FONT_CASE_U, FONT_CASE_V, FONT_CASE_W, FONT_CASE_X, FONT_CASE_Y,
+    FONT_CASE_Z, FONT_LEFT_CURLY_BRACKET, FONT_VERTICAL_LINE, FONT_RIGHT_CURLY_BRACKET,
+    FONT_TILDE,
+];
+
+// Glyphs of the Latin-1 characters, from the no-break space to 'ÿ'.
+const LATIN_1: &[Font<'static>] = &[
+    FONT_EMPTY, FONT_INVERTED_EXCLAMATION, FONT_CENT, FONT_POUND, FONT_CURRENCY,
+    FONT_YEN, FONT_BROKEN_BAR, FONT_SECTION, FONT_DIAERESIS, FONT_COPYRIGHT,
+    FONT_FEMININE_ORDINAL, FONT_LEFT_GUILLEMET, FONT_NOT, FONT_HYPHEN, FONT_REGISTERED,
+    FONT_MACRON, FONT_DEGREE, FONT_PLUS_MINUS, FONT_SUPERSCRIPT_TWO,
+    FONT_SUPERSCRIPT_THREE, FONT_ACUTE, FONT_MICRO, FONT_PILCROW, FONT_MIDDLE_DOT,
+    FONT_CEDILLA, FONT_SUPERSCRIPT_ONE, FONT_MASCULINE_ORDINAL, FONT_RIGHT_GUILLEMET,
+    FONT_ONE_QUARTER, FONT_ONE_HALF, FONT_THREE_QUARTERS, FONT_INVERTED_QUESTION,
+    FONT_A_GRAVE, FONT_A_ACUTE, FONT_A_CIRCUMFLEX, FONT_A_TILDE, FONT_A_DIAERESIS,
+    FONT_A_RING, FONT_AE, FONT_C_CEDILLA, FONT_E_GRAVE, FONT_E_ACUTE, FONT_E_CIRCUMFLEX,
+    FONT_E_DIAERESIS, FONT_I_GRAVE, FONT_I_ACUTE, FONT_I_CIRCUMFLEX, FONT_I_DIAERESIS,
+    FONT_ETH, FONT_N_TILDE, FONT_O_GRAVE, FONT_O_ACUTE, FONT_O_CIRCUMFLEX, FONT_O_TILDE,
+    FONT_O_DIAERESIS, FONT_MULTIPLICATION, FONT_O_STROKE, FONT_U_GRAVE, FONT_U_ACUTE,
+    FONT_U_CIRCUMFLEX, FONT_U_DIAERESIS, FONT_Y_ACUTE, FONT_THORN, FONT_SHARP_S,
+    FONT_CASE_A_GRAVE, FONT_CASE_A_ACUTE, FONT_CASE_A_CIRCUMFLEX, FONT_CASE_A_TILDE,
+    FONT_CASE_A_DIAERESIS, FONT_CASE_A_RING, FONT_CASE_AE, FONT_CASE_C_CEDILLA,
+    FONT_CASE_E_GRAVE, FONT_CASE_E_ACUTE, FONT_CASE_E_CIRCUMFLEX, FONT_CASE_E_DIAERESIS,
+    FONT_CASE_I_GRAVE, FONT_CASE_I_ACUTE, FONT_CASE_I_CIRCUMFLEX, FONT_CASE_I_DIAERESIS,
+    FONT_CASE_ETH, FONT_CASE_N_TILDE, FONT_CASE_O_GRAVE, FONT_CASE_O_ACUTE,
+    FONT_CASE_O_CIRCUMFLEX, FONT_CASE_O_TILDE, FONT_CASE_O_DIAERESIS, FONT_DIVISION,
+    FONT_CASE_O_STROKE, FONT_CASE_U_GRAVE, FONT_CASE_U_ACUTE, FONT_CASE_U_CIRCUMFLEX,
+    FONT_CASE_U_DIAERESIS, FONT_CASE_Y_ACUTE, FONT_CASE_THORN, FONT_CASE_Y_DIAERESIS,
+];
+
+pub fn glyph(c: char) -> Option<Font<'static>> {
+    match c as usize {
+        0x20..=0x7e => Some(ASCII[c as usize - 0x20]),
+        0xa0..=0xff => Some(LATIN_1[c as usize - 0xa0]),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_advance() {
+    assert_eq!(8, FONT_A.advance(None, 10));
+    assert_eq!(16, FONT_A.advance(Some('B'), 20));
+    assert_eq!(14, FONT_A.advance(Some('V'), 20));
+    assert_eq!(3, FONT_EMPTY.advance(Some('A'), 10));
+    assert_eq!(5, FONT_A.advance(Some('T'), 7));
+}
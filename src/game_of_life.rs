@@ -1,13 +1,22 @@
 use cells::*;
 use config::*;
 use wasm_bindgen::JsValue;
-use evolve::*;
+use engine::*;
+use rule::*;
+use verify::*;
+
+// Below this many generations stepping the board directly is faster than
+// building the Hashlife quadtree.
+const HASHLIFE_THRESHOLD: u64 = 4096;
 
 pub struct GameOfLife {
     width: f64,
     height: f64,
     cell_length: f64,
     cells: Cells,
+    rule: Rule,
+    stepper: Stepper,
+    hashlife: Hashlife,
 }
 
 impl GameOfLife {
@@ -19,11 +28,35 @@ impl GameOfLife {
             height: height,
             cell_length: cell_size as f64,
             cells: Cells::new(size_x, size_y),
+            rule: Rule::conway(),
+            stepper: Stepper::new(),
+            hashlife: Hashlife::new(),
         }
     }
 
+    pub fn size(&self) -> (usize, usize) {
+        (self.cells.size_x, self.cells.size_y)
+    }
+
+    pub fn set_topology(&mut self, topology: Topology) {
+        self.cells.topology = topology;
+    }
+
+    pub fn set_rule(&mut self, rule: Rule) {
+        self.rule = rule;
+    }
+
     pub fn evolve(&mut self) {
-        self.cells = next(&self.cells);
+        // Stepping a single generation cannot fail.
+        self.stepper.advance(&mut self.cells, &self.rule, 1).unwrap();
+    }
+
+    pub fn advance(&mut self, generations: u64) -> Result<(), String> {
+        if generations < HASHLIFE_THRESHOLD {
+            self.stepper.advance(&mut self.cells, &self.rule, generations)
+        } else {
+            self.hashlife.advance(&mut self.cells, &self.rule, generations)
+        }
     }
 
     pub fn clear(&mut self) {
@@ -34,6 +67,12 @@ impl GameOfLife {
         self.cells.allocate(cells, x, y, 1.0);
     }
 
+    // Removes the primes that would not survive on the board and returns how
+    // many there were.
+    pub fn repair(&mut self, placements: &mut Vec<Placement>) -> usize {
+        repair(&mut self.cells, &self.rule, placements)
+    }
+
     pub fn draw(&self, context: &web_sys::CanvasRenderingContext2d, config: &Config) {
         context.set_fill_style(&JsValue::from_str(config.background_color.as_str()));
         context.fill_rect(0.0, 0.0, self.width, self.height);
@@ -0,0 +1,224 @@
+use compound::*;
+use fitness::*;
+use mold::*;
+use optimizer::*;
+use primes::*;
+use randomizer::*;
+use rule::*;
+
+pub struct Genetic<T: Randomizer> {
+    pub randomizer: T,
+    pub population_size: usize,
+    pub generations: usize,
+    pub elites: usize,
+    pub mutation_rate: f64,
+    pub primes: Vec<Prime<'static>>,
+    pub fitness: Box<dyn Fitness>,
+}
+
+impl<T: Randomizer> Optimizer for Genetic<T> {
+    fn optimize(&mut self, mold: Mold) -> Compound {
+        let targets = targets(&mold);
+        if targets.is_empty() || self.population_size == 0 {
+            return Compound::new(mold.font_size);
+        }
+
+        let mut population: Vec<(Compound, f64)> = (0..self.population_size)
+            .map(|_| {
+                let pattern = self.spawn(&mold, &targets);
+                let score = self.fitness.score(&mold, &pattern);
+                (pattern, score)
+            })
+            .collect();
+        sort(&mut population);
+
+        for _ in 0..self.generations {
+            let mut next: Vec<(Compound, f64)> = population.iter()
+                .take(self.elites)
+                .cloned()
+                .collect();
+            while next.len() < self.population_size {
+                let a = self.select(&population);
+                let b = self.select(&population);
+                let mut child = self.crossover(&population[a].0, &population[b].0);
+                if self.randomizer.random_number() < self.mutation_rate {
+                    self.mutate(&mold, &mut child, &targets);
+                }
+                let score = self.fitness.score(&mold, &child);
+                next.push((child, score));
+            }
+            sort(&mut next);
+            population = next;
+        }
+
+        population.swap_remove(0).0
+    }
+
+    fn set_fitness(&mut self, fitness: Box<dyn Fitness>) {
+        self.fitness = fitness;
+    }
+
+    fn set_primes(&mut self, primes: Vec<Prime<'static>>) {
+        self.primes = primes;
+    }
+}
+
+impl<T: Randomizer> Genetic<T> {
+    pub fn new(randomizer: T, population_size: usize, generations: usize, rule: &Rule) -> Result<Self, String> {
+        Ok(Genetic {
+            randomizer: randomizer,
+            population_size: population_size,
+            generations: generations,
+            elites: 2,
+            mutation_rate: 0.8,
+            primes: stable_primes(rule)?,
+            fitness: default_fitness(),
+        })
+    }
+
+    fn random_index(&mut self, len: usize) -> usize {
+        ((self.randomizer.random_number() * len as f64) as usize).min(len - 1)
+    }
+
+    // A random compound with primes dropped over the target cells.
+    fn spawn(&mut self, mold: &Mold, targets: &[(usize, usize)]) -> Compound {
+        let mut pattern = Compound::new(mold.font_size);
+        for _ in 0..targets.len() / 4 {
+            self.add(mold, &mut pattern, targets);
+        }
+        pattern
+    }
+
+    // Picks the better of two random individuals. The population is sorted,
+    // so the smaller index wins.
+    fn select(&mut self, population: &[(Compound, f64)]) -> usize {
+        let a = self.random_index(population.len());
+        let b = self.random_index(population.len());
+        a.min(b)
+    }
+
+    // Takes the primes on one side of a random horizontal or vertical cut from
+    // `a` and those on the other side from `b`, dropping any of `b` that would
+    // overlap across the cut.
+    fn crossover(&mut self, a: &Compound, b: &Compound) -> Compound {
+        let vertical = self.randomizer.random_number() < 0.5;
+        let cut = self.random_index(a.font_size + 1);
+        let before = |x: usize, y: usize| if vertical { x < cut } else { y < cut };
+
+        let mut child = Compound::new(a.font_size);
+        for (x, y, p) in a.placements() {
+            if before(x, y) {
+                child.set(p, x, y);
+            }
+        }
+        for (x, y, p) in b.placements() {
+            if !before(x, y) && child.fits(&p, x, y) {
+                child.set(p, x, y);
+            }
+        }
+        child
+    }
+
+    fn mutate(&mut self, mold: &Mold, pattern: &mut Compound, targets: &[(usize, usize)]) {
+        let placements = pattern.placements();
+        let r = self.randomizer.random_number();
+        if placements.is_empty() || r < 0.4 {
+            self.add(mold, pattern, targets);
+            return;
+        }
+
+        let (x, y, p) = placements[self.random_index(placements.len())].clone();
+        pattern.remove(x, y);
+        if r < 0.7 {
+            return;
+        }
+        let q = fitting_prime(&mut self.randomizer, &self.primes, mold, |_| (x, y));
+        if pattern.fits(&q, x, y) {
+            pattern.set(q, x, y);
+        } else {
+            pattern.set(p, x, y);
+        }
+    }
+
+    fn add(&mut self, mold: &Mold, pattern: &mut Compound, targets: &[(usize, usize)]) {
+        let (tx, ty) = targets[self.random_index(targets.len())];
+        let (p, x, y) = place_near(&mut self.randomizer, &self.primes, mold, tx, ty);
+        if pattern.fits(&p, x, y) {
+            pattern.set(p, x, y);
+        }
+    }
+}
+
+// Best first. The sort is stable, so ties keep their order and runs stay
+// reproducible.
+fn sort(population: &mut [(Compound, f64)]) {
+    population.sort_by(|a, b| b.1.total_cmp(&a.1));
+}
+
+#[test]
+fn test_optimize_is_reproducible() {
+    let optimize = |seed| {
+        let mut optimizer = Genetic::new(SplitMix::from_seed(seed), 10, 10, &Rule::conway()).unwrap();
+        optimizer.optimize(Mold::from_char('R', 30))
+    };
+
+    assert_eq!(optimize(7), optimize(7));
+    assert_ne!(optimize(7), optimize(8));
+}
+
+#[test]
+fn test_optimize_places_primes_that_fit() {
+    for seed in 0..4 {
+        for c in "AgW8x".chars() {
+            let mut optimizer = Genetic::new(SplitMix::from_seed(seed), 10, 10, &Rule::conway()).unwrap();
+            let compound = optimizer.optimize(Mold::from_char(c, 30));
+
+            let mut rebuilt = Compound::new(compound.font_size);
+            for (x, y, p) in compound.placements() {
+                assert!(rebuilt.fits(&p, x, y));
+                rebuilt.set(p, x, y);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_elitism_never_decreases_best_score() {
+    let mold = Mold::from_char('B', 30);
+    let best = |generations| {
+        let mut optimizer = Genetic::new(SplitMix::from_seed(3), 10, generations, &Rule::conway()).unwrap();
+        evaluate(&mold, &optimizer.optimize(Mold::from_char('B', 30)))
+    };
+
+    let mut score = best(0);
+    for generations in 1..8 {
+        let improved = best(generations);
+        assert!(improved >= score, "{} < {}", improved, score);
+        score = improved;
+    }
+}
+
+#[test]
+fn test_crossover_keeps_sides() {
+    let mut optimizer = Genetic::new(SplitMix::from_seed(0), 10, 10, &Rule::conway()).unwrap();
+    let mut a = Compound::new(20);
+    a.set(BLOCK, 0, 0);
+    a.set(BLOCK, 12, 12);
+    let mut b = Compound::new(20);
+    b.set(TUB, 1, 1);
+    b.set(TUB, 14, 14);
+
+    for _ in 0..20 {
+        let child = optimizer.crossover(&a, &b);
+        for (x, y, p) in child.placements() {
+            assert!(a.pattern[x][y] == p || b.pattern[x][y] == p);
+        }
+    }
+}
+
+#[test]
+fn test_optimize_empty_mold() {
+    let mut optimizer = Genetic::new(SplitMix::from_seed(0), 10, 10, &Rule::conway()).unwrap();
+
+    assert!(optimizer.optimize(Mold::from_char(' ', 20)).placements().is_empty());
+}
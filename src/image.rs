@@ -0,0 +1,80 @@
+use cells::*;
+
+// Turns an RGBA image, row by row with 4 bytes per pixel, into cells. Pixels
+// darker than `threshold`, a lightness between 0 and 1, are alive; transparent
+// pixels count as white. With `dither`, the error of every pixel is spread to
+// its neighbours (Floyd-Steinberg) so shades of grey become patterns of cells.
+pub fn to_cells(rgba: &[u8], width: usize, height: usize, threshold: f64, dither: bool) -> Result<Cells, String> {
+    let expected = match width.checked_mul(height).and_then(|n| n.checked_mul(4)) {
+        Some(n) => n,
+        None => return Err(format!("image is too large: {}x{}", width, height)),
+    };
+    if rgba.len() != expected {
+        return Err(format!("expected {} bytes for a {}x{} image, got {}", expected, width, height, rgba.len()));
+    }
+    if !(0.0..=1.0).contains(&threshold) {
+        return Err(format!("threshold must be between 0 and 1: {}", threshold));
+    }
+
+    let mut lightness: Vec<f64> = rgba.chunks(4)
+        .map(|p| {
+            let luma = (0.2126 * p[0] as f64 + 0.7152 * p[1] as f64 + 0.0722 * p[2] as f64) / 255.0;
+            let alpha = p[3] as f64 / 255.0;
+            luma * alpha + 1.0 - alpha
+        })
+        .collect();
+
+    let mut cells = Cells::new(width, height);
+    for y in 0..height {
+        for x in 0..width {
+            let value = lightness[y * width + x];
+            let alive = value < threshold;
+            if alive {
+                cells.make_alive(x, y);
+            }
+            if !dither {
+                continue;
+            }
+            let error = value - if alive { 0.0 } else { 1.0 };
+            let neighbours = [(1, 0, 7.0), (-1, 1, 3.0), (0, 1, 5.0), (1, 1, 1.0)];
+            for &(dx, dy, weight) in neighbours.iter() {
+                let (nx, ny) = (x as isize + dx, y + dy);
+                if nx >= 0 && (nx as usize) < width && ny < height {
+                    lightness[ny * width + nx as usize] += error * weight / 16.0;
+                }
+            }
+        }
+    }
+    Ok(cells)
+}
+
+#[cfg(test)]
+fn grey(width: usize, height: usize, value: u8) -> Vec<u8> {
+    (0..width * height).flat_map(|_| vec![value, value, value, 255]).collect()
+}
+
+#[test]
+fn test_to_cells() {
+    let mut rgba = grey(3, 2, 255);
+    // A black pixel, a transparent black pixel and a dark red pixel.
+    rgba[0..4].copy_from_slice(&[0, 0, 0, 255]);
+    rgba[4..8].copy_from_slice(&[0, 0, 0, 0]);
+    rgba[12..16].copy_from_slice(&[128, 0, 0, 255]);
+    let cells = to_cells(&rgba, 3, 2, 0.5, false).unwrap();
+
+    assert_eq!(Cells::from_vec(vec![vec![1, 0, 0], vec![1, 0, 0]]), cells);
+    assert_eq!(0, to_cells(&rgba, 3, 2, 0.0, false).unwrap().count_alive());
+    assert!(to_cells(&rgba, 4, 2, 0.5, false).is_err());
+    assert!(to_cells(&rgba, 3, 2, 1.5, false).is_err());
+    assert!(to_cells(&rgba, usize::MAX / 2, 3, 0.5, false).is_err());
+    assert!(to_cells(&[], usize::MAX / 4 + 1, 4, 0.5, false).is_err());
+}
+
+#[test]
+fn test_dither() {
+    let rgba = grey(8, 8, 128);
+
+    assert_eq!(0, to_cells(&rgba, 8, 8, 0.5, false).unwrap().count_alive());
+    let alive = to_cells(&rgba, 8, 8, 0.5, true).unwrap().count_alive();
+    assert!((28..=36).contains(&alive), "{}", alive);
+}
@@ -0,0 +1,409 @@
+use std::str::FromStr;
+use cells::*;
+use compound::*;
+use config::*;
+use primes::*;
+
+// Stands in for the tiles of an image among the characters of a line.
+const OBJECT_REPLACEMENT: char = '\u{fffc}';
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+impl FromStr for Align {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Align, String> {
+        match s.to_lowercase().as_str() {
+            "left" => Ok(Align::Left),
+            "center" => Ok(Align::Center),
+            "right" => Ok(Align::Right),
+            _ => Err(format!("unknown alignment: {}", s)),
+        }
+    }
+}
+
+pub struct Glyph {
+    pub x: usize,
+    pub y: usize,
+    pub cells: Cells,
+    // The primes the glyph is made of, relative to the top left of `cells`.
+    pub primes: Vec<(i64, i64, Prime<'static>)>,
+}
+
+struct Line {
+    glyphs: Vec<Glyph>,
+    width: usize,
+    cursor: usize,
+    // (index of the first glyph of the last word, x where the word starts, line width before it)
+    wrap: Option<(usize, usize, usize)>,
+}
+
+impl Line {
+    fn new() -> Line {
+        Line {
+            glyphs: Vec::new(),
+            width: 0,
+            cursor: 0,
+            wrap: None,
+        }
+    }
+
+    fn split(&mut self) -> Option<Line> {
+        let (index, x, width) = self.wrap.take()?;
+        if index == 0 || index >= self.glyphs.len() {
+            return None;
+        }
+
+        let mut glyphs = self.glyphs.split_off(index);
+        for glyph in glyphs.iter_mut() {
+            glyph.x -= x;
+        }
+        let line = Line {
+            glyphs: glyphs,
+            width: self.width - x,
+            cursor: self.cursor - x,
+            wrap: None,
+        };
+        self.width = width;
+        self.cursor = width;
+        Some(line)
+    }
+}
+
+pub struct Layout {
+    pub glyphs: Vec<Glyph>,
+    pub width: usize,
+    pub height: usize,
+    margin: usize,
+    letter_spacing: usize,
+    line_spacing: usize,
+    line_height: usize,
+    align: Align,
+    wrap_width: Option<usize>,
+    lines: Vec<Line>,
+}
+
+impl Layout {
+    pub fn new(config: &Config, board_width: usize) -> Layout {
+        let wrap_width = if config.word_wrap {
+            Some(board_width.saturating_sub(config.margin * 2))
+        } else {
+            None
+        };
+
+        Layout {
+            glyphs: Vec::new(),
+            width: config.margin * 2,
+            height: config.margin * 2,
+            margin: config.margin,
+            letter_spacing: config.letter_spacing,
+            line_spacing: config.line_spacing,
+            line_height: config.font_size,
+            align: config.align,
+            wrap_width: wrap_width,
+            lines: vec![Line::new()],
+        }
+    }
+
+    pub fn new_line(&mut self) {
+        self.lines.push(Line::new());
+    }
+
+    #[cfg(test)]
+    pub fn push(&mut self, c: char, cells: Cells, advance: usize) {
+        self.push_glyph(c, cells, Vec::new(), advance);
+    }
+
+    // Places the glyph at the cursor and moves the cursor on by `advance`
+    // cells, or the width of the glyph if it is wider, plus the letter spacing.
+    pub fn push_compound(&mut self, c: char, pattern: &Compound, advance: usize) {
+        self.push_glyph(c, pattern.to_cells(), pattern.placements(), advance);
+    }
+
+    // Places the tiles of an image `width` cells wide side by side, without
+    // letter spacing between them.
+    pub fn push_image(&mut self, tiles: &[Compound], width: usize) {
+        let letter_spacing = self.letter_spacing;
+        let mut remaining = width;
+        for (i, tile) in tiles.iter().enumerate() {
+            let advance = tile.font_size.min(remaining);
+            remaining -= advance;
+            self.letter_spacing = if i + 1 == tiles.len() { letter_spacing } else { 0 };
+            self.push_compound(OBJECT_REPLACEMENT, tile, advance);
+        }
+        self.letter_spacing = letter_spacing;
+    }
+
+    fn push_glyph(&mut self, c: char, cells: Cells, primes: Vec<(usize, usize, Prime<'static>)>, advance: usize) {
+        if c == '\n' {
+            self.new_line();
+            return;
+        }
+
+        let letter_spacing = self.letter_spacing;
+        let wrap_width = self.wrap_width;
+        let split = {
+            let line = self.lines.last_mut().unwrap();
+            let columns = cells.columns();
+            // A glyph drawn wider than its advance pushes the next one along,
+            // so that their primes never overlap.
+            let step = columns.map_or(advance, |(_, right)| advance.max(right + 1)) + letter_spacing;
+            // Spaces are drawn too when they have cells, as in inverted text.
+            if let Some((_, right)) = columns {
+                let width = right + 1;
+                line.glyphs.push(Glyph {
+                    x: line.cursor,
+                    y: 0,
+                    cells: cells.crop(0, 0, width, cells.size_y),
+                    primes: primes.iter().map(|&(x, y, ref p)| (x as i64, y as i64, p.clone())).collect(),
+                });
+                line.width = line.width.max(line.cursor + width);
+            }
+            // Blank tiles of an image are not gaps between words.
+            if c != OBJECT_REPLACEMENT && (columns.is_none() || c.is_whitespace()) {
+                line.wrap = Some((line.glyphs.len(), line.cursor + step, line.width));
+            }
+            line.cursor += step;
+
+            match wrap_width {
+                Some(w) if line.width > w => line.split(),
+                _ => None,
+            }
+        };
+
+        if let Some(line) = split {
+            self.lines.push(line);
+        }
+    }
+
+    pub fn finish(&mut self) {
+        let text_width = self.lines.iter().map(|l| l.width).max().unwrap_or(0);
+        let line_count = self.lines.len();
+
+        for (i, line) in self.lines.drain(..).enumerate() {
+            let offset = match self.align {
+                Align::Left => 0,
+                Align::Center => (text_width - line.width) / 2,
+                Align::Right => text_width - line.width,
+            };
+            let y = self.margin + i * (self.line_height + self.line_spacing);
+            for glyph in line.glyphs {
+                self.glyphs.push(Glyph {
+                    x: self.margin + offset + glyph.x,
+                    y: y + glyph.y,
+                    cells: glyph.cells,
+                    primes: glyph.primes,
+                });
+            }
+        }
+
+        self.width = text_width + self.margin * 2;
+        self.height = line_count * self.line_height
+            + (line_count - 1) * self.line_spacing
+            + self.margin * 2;
+    }
+}
+
+#[cfg(test)]
+fn test_config() -> Config {
+    let mut config = Config::new();
+    config.font_size = 4;
+    config.margin = 5;
+    config.letter_spacing = 2;
+    config.line_spacing = 3;
+    config
+}
+
+#[cfg(test)]
+fn test_block() -> Cells {
+    Cells::from_vec(vec![
+        vec![0, 0, 0, 0],
+        vec![0, 1, 1, 0],
+        vec![0, 1, 1, 0],
+        vec![0, 0, 0, 0],
+    ])
+}
+
+#[test]
+fn test_push() {
+    let mut layout = Layout::new(&test_config(), 100);
+    layout.push('a', test_block(), 4);
+    layout.push(' ', Cells::new(6, 4), 2);
+    layout.push('b', Cells::from_vec(vec![
+        vec![1, 0, 0, 0],
+        vec![0, 0, 0, 0],
+        vec![0, 0, 0, 0],
+        vec![0, 0, 0, 1],
+    ]), 4);
+    layout.finish();
+
+    assert_eq!(2, layout.glyphs.len());
+    assert_eq!((5, 5), (layout.glyphs[0].x, layout.glyphs[0].y));
+    assert_eq!((3, 4), (layout.glyphs[0].cells.size_x, layout.glyphs[0].cells.size_y));
+    assert_eq!((15, 5), (layout.glyphs[1].x, layout.glyphs[1].y));
+    assert_eq!((4, 4), (layout.glyphs[1].cells.size_x, layout.glyphs[1].cells.size_y));
+    assert_eq!(24, layout.width);
+    assert_eq!(14, layout.height);
+}
+
+#[test]
+fn test_new_line() {
+    let mut layout = Layout::new(&test_config(), 100);
+    layout.push('a', test_block(), 4);
+    layout.push('a', test_block(), 4);
+    layout.push('\n', Cells::new(4, 4), 4);
+    layout.push('a', test_block(), 4);
+    layout.finish();
+
+    assert_eq!(3, layout.glyphs.len());
+    assert_eq!((11, 5), (layout.glyphs[1].x, layout.glyphs[1].y));
+    assert_eq!((5, 12), (layout.glyphs[2].x, layout.glyphs[2].y));
+    assert_eq!(19, layout.width);
+    assert_eq!(21, layout.height);
+}
+
+#[test]
+fn test_align() {
+    let mut config = test_config();
+    config.align = Align::Right;
+    let mut layout = Layout::new(&config, 100);
+    layout.push('a', test_block(), 4);
+    layout.push('a', test_block(), 4);
+    layout.push('\n', Cells::new(4, 4), 4);
+    layout.push('a', test_block(), 4);
+    layout.finish();
+
+    assert_eq!((11, 12), (layout.glyphs[2].x, layout.glyphs[2].y));
+
+    config.align = Align::Center;
+    let mut layout = Layout::new(&config, 100);
+    layout.push('a', test_block(), 4);
+    layout.push('a', test_block(), 4);
+    layout.push('a', test_block(), 4);
+    layout.push('\n', Cells::new(4, 4), 4);
+    layout.push('a', test_block(), 4);
+    layout.finish();
+
+    assert_eq!((11, 12), (layout.glyphs[3].x, layout.glyphs[3].y));
+}
+
+#[test]
+fn test_word_wrap() {
+    let mut config = test_config();
+    config.word_wrap = true;
+    let mut layout = Layout::new(&config, 24);
+    layout.push('a', test_block(), 4);
+    layout.push('a', test_block(), 4);
+    layout.push(' ', Cells::new(3, 4), 1);
+    layout.push('a', test_block(), 4);
+    layout.push('a', test_block(), 4);
+    layout.finish();
+
+    assert_eq!(4, layout.glyphs.len());
+    assert_eq!((11, 5), (layout.glyphs[1].x, layout.glyphs[1].y));
+    assert_eq!((5, 12), (layout.glyphs[2].x, layout.glyphs[2].y));
+    assert_eq!((11, 12), (layout.glyphs[3].x, layout.glyphs[3].y));
+    assert_eq!(19, layout.width);
+    assert_eq!(21, layout.height);
+}
+
+#[test]
+fn test_push_filled_space() {
+    let mut config = test_config();
+    config.word_wrap = true;
+    let mut layout = Layout::new(&config, 24);
+    layout.push('a', test_block(), 4);
+    layout.push(' ', test_block(), 4);
+    layout.push('a', test_block(), 4);
+    layout.push('a', test_block(), 4);
+    layout.finish();
+
+    // The space is drawn, and the line still wraps after it.
+    assert_eq!(4, layout.glyphs.len());
+    assert_eq!((11, 5), (layout.glyphs[1].x, layout.glyphs[1].y));
+    assert_eq!((5, 12), (layout.glyphs[2].x, layout.glyphs[2].y));
+}
+
+#[test]
+fn test_push_compound() {
+    let mut layout = Layout::new(&test_config(), 100);
+    let mut pattern = Compound::new(12);
+    pattern.set(BLINKER, 0, 0);
+    pattern.set(BLOCK, 5, 6);
+    layout.push_compound('a', &pattern, 8);
+    layout.push_compound('b', &pattern, 8);
+    layout.finish();
+
+    let glyph = &layout.glyphs[0];
+    assert_eq!((5, 5), (glyph.x, glyph.y));
+    assert_eq!(vec![(0, 0, BLINKER), (5, 6, BLOCK)], glyph.primes);
+    assert!(glyph.cells.is_alive(2, 1));
+    assert!(glyph.cells.is_alive(6, 7));
+    assert_eq!((15, 5), (layout.glyphs[1].x, layout.glyphs[1].y));
+}
+
+#[test]
+fn test_push_image() {
+    let mut layout = Layout::new(&test_config(), 100);
+    let mut tile = Compound::new(4);
+    tile.set(BLOCK, 0, 0);
+    layout.push_image(&[tile.clone(), Compound::new(4), tile.clone()], 12);
+    layout.push_compound('a', &tile, 4);
+    layout.finish();
+
+    assert_eq!(3, layout.glyphs.len());
+    assert_eq!(5, layout.glyphs[0].x);
+    assert_eq!(13, layout.glyphs[1].x);
+    assert_eq!(19, layout.glyphs[2].x);
+
+    // The last tile of an image 11 cells wide only advances by three cells.
+    let mut layout = Layout::new(&test_config(), 100);
+    layout.push_image(&[tile.clone(), Compound::new(4), tile.clone()], 11);
+    layout.push_compound('a', &tile, 4);
+    layout.finish();
+
+    assert_eq!(18, layout.glyphs[2].x);
+}
+
+#[test]
+fn test_push_glyph_wider_than_advance() {
+    let mut layout = Layout::new(&test_config(), 100);
+    layout.push('a', test_block(), 1);
+    layout.push('a', test_block(), 4);
+    layout.finish();
+
+    // The block is three columns wide, so the next glyph starts after it.
+    assert_eq!(10, layout.glyphs[1].x);
+    assert_eq!(18, layout.width);
+}
+
+#[test]
+fn test_word_wrap_keeps_images_whole() {
+    let mut config = test_config();
+    config.word_wrap = true;
+    let mut layout = Layout::new(&config, 24);
+    let mut tile = Compound::new(4);
+    tile.set(BLOCK, 0, 0);
+    layout.push('a', test_block(), 4);
+    layout.push(' ', Cells::new(3, 4), 1);
+    layout.push_image(&[tile.clone(), Compound::new(4), tile.clone()], 12);
+    layout.finish();
+
+    // The image wraps after the space, not at its blank middle tile.
+    assert_eq!(3, layout.glyphs.len());
+    assert_eq!((5, 12), (layout.glyphs[1].x, layout.glyphs[1].y));
+    assert_eq!((13, 12), (layout.glyphs[2].x, layout.glyphs[2].y));
+}
+
+#[test]
+fn test_align_from_str() {
+    assert_eq!(Ok(Align::Left), "left".parse());
+    assert_eq!(Ok(Align::Center), "Center".parse());
+    assert_eq!(Ok(Align::Right), "RIGHT".parse());
+    assert!("middle".parse::<Align>().is_err());
+}
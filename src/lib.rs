@@ -1,27 +1,50 @@
 extern crate cfg_if;
 extern crate rand;
+#[cfg(feature = "truetype")]
+extern crate ttf_parser;
 extern crate wasm_bindgen;
 extern crate web_sys;
 
+mod annealing;
+mod bitmap_font;
 mod cells;
 mod compound;
 mod config;
+mod engine;
 mod evolve;
+mod exact;
+mod fitness;
 mod fonts;
 mod game_of_life;
+mod genetic;
+mod image;
+mod layout;
 mod mold;
 mod optimizer;
 mod primes;
 mod randomizer;
+mod resample;
+mod raster;
+mod rle;
+mod rule;
+mod svg;
+#[cfg(feature = "truetype")]
+mod truetype;
 mod utils;
+mod verify;
 
 use wasm_bindgen::JsCast;
 use wasm_bindgen::prelude::*;
 use game_of_life::GameOfLife;
+use cells::Topology;
+use compound::Compound;
 use config::*;
+use layout::*;
 use mold::*;
-use optimizer::*;
+use primes::{Prime, stable_primes_with};
 use randomizer::*;
+use rule::Rule;
+use svg::Svg;
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "wee_alloc")] {
@@ -35,6 +58,9 @@ pub struct Banner {
     canvas_id: String,
     game_of_life: GameOfLife,
     config: Config,
+    text_width: usize,
+    text_height: usize,
+    removed_primes: usize,
 }
 
 #[wasm_bindgen]
@@ -46,6 +72,9 @@ impl Banner {
             canvas_id: canvas_id.to_string(),
             game_of_life: GameOfLife::new(width, height, config.cell_size),
             config: config,
+            text_width: 0,
+            text_height: 0,
+            removed_primes: 0,
         }
     }
 
@@ -68,6 +97,8 @@ impl Banner {
         self.config.cell_size = cell_size;
         let (width, height) = Banner::get_canvas_size(self.canvas_id.as_str());
         self.game_of_life =  GameOfLife::new(width, height, cell_size);
+        self.game_of_life.set_rule(self.config.rule);
+        self.game_of_life.set_topology(self.config.topology);
     }
 
     #[wasm_bindgen(js_name = setFontSize)]
@@ -75,6 +106,186 @@ impl Banner {
         self.config.font_size = font_size;
     }
 
+    // Draws text with a BDF, PSF or (with the truetype feature) TrueType font
+    // instead of the built-in one.
+    #[wasm_bindgen(js_name = loadFont)]
+    pub fn load_font(&mut self, bytes: &[u8]) -> Result<(), JsValue> {
+        self.config.font = load_typeface(bytes).map_err(|e| JsValue::from_str(&e))?;
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = clearFont)]
+    pub fn clear_font(&mut self) {
+        self.config.font = Box::new(BuiltIn);
+    }
+
+    // Draws `c` with an SVG path or document instead of its glyph.
+    #[wasm_bindgen(js_name = setIcon)]
+    pub fn set_icon(&mut self, c: char, svg: &str, fill_rule: &str) -> Result<(), JsValue> {
+        let fill_rule = fill_rule.parse().map_err(|e: String| JsValue::from_str(&e))?;
+        let svg = Svg::parse(svg, fill_rule).map_err(|e| JsValue::from_str(&e))?;
+        self.config.icons.insert(c, svg);
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = clearIcons)]
+    pub fn clear_icons(&mut self) {
+        self.config.icons.clear();
+    }
+
+    // Draws `c` with an RGBA image instead of its glyph, so that images can
+    // be rendered among the text. See renderImage for the arguments.
+    #[wasm_bindgen(js_name = setImage)]
+    pub fn set_image(&mut self, c: char, rgba: &[u8], width: usize, height: usize, threshold: f64) -> Result<(), JsValue> {
+        let cells = image::to_cells(rgba, width, height, threshold, self.config.dither).map_err(|e| JsValue::from_str(&e))?;
+        self.config.images.insert(c, cells);
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = clearImages)]
+    pub fn clear_images(&mut self) {
+        self.config.images.clear();
+    }
+
+    #[wasm_bindgen(js_name = setTextStyle)]
+    pub fn set_text_style(&mut self, text_style: &str) -> Result<(), JsValue> {
+        self.config.text_style = text_style.parse().map_err(|e: String| JsValue::from_str(&e))?;
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = setLetterSpacing)]
+    pub fn set_letter_spacing(&mut self, letter_spacing: usize) {
+        self.config.letter_spacing = letter_spacing;
+    }
+
+    #[wasm_bindgen(js_name = setLineSpacing)]
+    pub fn set_line_spacing(&mut self, line_spacing: usize) {
+        self.config.line_spacing = line_spacing;
+    }
+
+    #[wasm_bindgen(js_name = setAlign)]
+    pub fn set_align(&mut self, align: &str) -> Result<(), JsValue> {
+        self.config.align = align.parse().map_err(|e: String| JsValue::from_str(&e))?;
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = setWordWrap)]
+    pub fn set_word_wrap(&mut self, word_wrap: bool) {
+        self.config.word_wrap = word_wrap;
+    }
+
+    #[wasm_bindgen(js_name = setMargin)]
+    pub fn set_margin(&mut self, margin: usize) {
+        self.config.margin = margin;
+    }
+
+    #[wasm_bindgen(js_name = textWidth)]
+    pub fn text_width(&self) -> usize {
+        self.text_width * self.config.cell_size
+    }
+
+    #[wasm_bindgen(js_name = textHeight)]
+    pub fn text_height(&self) -> usize {
+        self.text_height * self.config.cell_size
+    }
+
+    // The number of primes the last `render` had to remove because they
+    // interacted with their neighbours or the board edge.
+    #[wasm_bindgen(js_name = removedPrimes)]
+    pub fn removed_primes(&self) -> usize {
+        self.removed_primes
+    }
+
+    #[wasm_bindgen(js_name = setRule)]
+    pub fn set_rule(&mut self, rule: &str) -> Result<(), JsValue> {
+        let rule: Rule = rule.parse().map_err(|e: String| JsValue::from_str(&e))?;
+        stable_primes_with(&rule, &self.config.primes).map_err(|e| JsValue::from_str(&e))?;
+        self.config.rule = rule;
+        self.game_of_life.set_rule(rule);
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = setTopology)]
+    pub fn set_topology(&mut self, topology: &str) -> Result<(), JsValue> {
+        let topology: Topology = topology.parse().map_err(|e: String| JsValue::from_str(&e))?;
+        self.config.topology = topology;
+        self.game_of_life.set_topology(topology);
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = setSeed)]
+    pub fn set_seed(&mut self, seed: u64) {
+        self.config.seed = Some(seed);
+    }
+
+    #[wasm_bindgen(js_name = clearSeed)]
+    pub fn clear_seed(&mut self) {
+        self.config.seed = None;
+    }
+
+    #[wasm_bindgen(js_name = setOptimizer)]
+    pub fn set_optimizer(&mut self, optimizer: &str) -> Result<(), JsValue> {
+        self.config.optimizer = optimizer.parse().map_err(|e: String| JsValue::from_str(&e))?;
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = setGradientPasses)]
+    pub fn set_gradient_passes(&mut self, gradient_passes: usize) {
+        self.config.gradient_passes = gradient_passes;
+    }
+
+    #[wasm_bindgen(js_name = setAnnealingSteps)]
+    pub fn set_annealing_steps(&mut self, annealing_steps: usize) {
+        self.config.annealing_steps = annealing_steps;
+    }
+
+    #[wasm_bindgen(js_name = setNodeLimit)]
+    pub fn set_node_limit(&mut self, node_limit: usize) {
+        self.config.node_limit = node_limit;
+    }
+
+    #[wasm_bindgen(js_name = setFitness)]
+    pub fn set_fitness(&mut self, fitness: &str) -> Result<(), JsValue> {
+        self.config.fitness = fitness.parse().map_err(|e: String| JsValue::from_str(&e))?;
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = setPhaseScoring)]
+    pub fn set_phase_scoring(&mut self, phase_scoring: &str) -> Result<(), JsValue> {
+        self.config.phase_scoring = phase_scoring.parse().map_err(|e: String| JsValue::from_str(&e))?;
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = setResampling)]
+    pub fn set_resampling(&mut self, resampling: &str) -> Result<(), JsValue> {
+        self.config.resampling = resampling.parse().map_err(|e: String| JsValue::from_str(&e))?;
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = setDithering)]
+    pub fn set_dithering(&mut self, dither: bool) {
+        self.config.dither = dither;
+    }
+
+    #[wasm_bindgen(js_name = setPopulationSize)]
+    pub fn set_population_size(&mut self, population_size: usize) {
+        self.config.population_size = population_size;
+    }
+
+    #[wasm_bindgen(js_name = setGenerations)]
+    pub fn set_generations(&mut self, generations: usize) {
+        self.config.generations = generations;
+    }
+
+    // Adds a still life or oscillator given in RLE or plaintext to the primes
+    // glyphs are drawn with. It has to be stable under the current rule.
+    #[wasm_bindgen(js_name = addPrime)]
+    pub fn add_prime(&mut self, pattern: &str) -> Result<(), JsValue> {
+        let prime = Prime::from_pattern(pattern, &self.config.rule).map_err(|e| JsValue::from_str(&e))?;
+        self.config.primes.push(prime);
+        Ok(())
+    }
+
     #[wasm_bindgen(js_name = setBackgroundColor)]
     pub fn set_background_color(&mut self, background_color: &str) {
         self.config.background_color = background_color.to_string();
@@ -90,22 +301,23 @@ impl Banner {
         self.config.grid_color = grid_color.to_string();
     }
 
-    pub fn render(&mut self, text: &str) {
-       let rand = Rand::new();
-       let mut optimizer = GradientDescent {
-            randomizer: rand,
-            n: 5,
+    pub fn render(&mut self, text: &str) -> Result<(), JsValue> {
+        let result = match self.config.seed {
+            Some(seed) => self.render_with(text, SplitMix::from_seed(seed)),
+            None => self.render_with(text, Rand::new()),
         };
-        let cs: Vec<char> = text.chars().collect();
-        let mut i = 0;
-        for c in cs {
-            let mold = Mold::from_char(c, self.config.font_size);
-            let pattern = optimizer.optimize(mold);
-            let cells = pattern.to_cells();
-            self.game_of_life.clear();
-            self.game_of_life.allocate(cells, 5 + i * self.config.font_size, 5);
-            i += 1;
-        }
+        result.map_err(|e| JsValue::from_str(&e))
+    }
+
+    // Draws an RGBA image, such as the data of a canvas `ImageData`, scaled
+    // to the font size in height.
+    #[wasm_bindgen(js_name = renderImage)]
+    pub fn render_image(&mut self, rgba: &[u8], width: usize, height: usize, threshold: f64) -> Result<(), JsValue> {
+        let result = match self.config.seed {
+            Some(seed) => self.render_image_with(rgba, width, height, threshold, SplitMix::from_seed(seed)),
+            None => self.render_image_with(rgba, width, height, threshold, Rand::new()),
+        };
+        result.map_err(|e| JsValue::from_str(&e))
     }
 
     pub fn tick(&mut self) {
@@ -113,6 +325,12 @@ impl Banner {
         self.draw();
     }
 
+    pub fn advance(&mut self, generations: u32) -> Result<(), JsValue> {
+        self.game_of_life.advance(generations as u64).map_err(|e| JsValue::from_str(&e))?;
+        self.draw();
+        Ok(())
+    }
+
     pub fn draw(&self) {
         let context = web_sys::window()
             .unwrap()
@@ -132,3 +350,74 @@ impl Banner {
         self.game_of_life.draw(&context, &self.config);
     }
 }
+
+impl Banner {
+    fn render_with<T: Randomizer + 'static>(&mut self, text: &str, randomizer: T) -> Result<(), String> {
+        let mut optimizer = optimizer::from_config(&self.config, randomizer)?;
+        let (board_width, _) = self.game_of_life.size();
+        let mut layout = Layout::new(&self.config, board_width);
+        let font_size = self.config.font_size;
+        let chars: Vec<char> = text.chars().collect();
+        for (i, &c) in chars.iter().enumerate() {
+            if c == '\n' {
+                layout.new_line();
+                continue;
+            }
+            if let Some(cells) = self.config.images.get(&c) {
+                let tiles: Vec<Compound> = Mold::from_bitmap(cells, font_size, self.config.resampling)
+                    .into_iter()
+                    .map(|mold| optimizer.optimize(mold))
+                    .collect();
+                layout.push_image(&tiles, Mold::bitmap_width(cells, font_size));
+                continue;
+            }
+            let font = &self.config.font;
+            let (advance, mold) = match self.config.icons.get(&c) {
+                Some(svg) => (svg.width(font_size), Mold::from_svg(svg, font_size)),
+                None => (
+                    font.advance(c, chars.get(i + 1).cloned(), font_size),
+                    font.mold(c, font_size, self.config.resampling),
+                ),
+            };
+            let style = self.config.text_style;
+            let advance = style.advance(advance, font_size);
+            let mold = mold.styled(style, advance + self.config.letter_spacing);
+            let pattern = optimizer.optimize(mold);
+            layout.push_compound(c, &pattern, advance);
+        }
+        self.place(layout);
+        Ok(())
+    }
+
+    fn render_image_with<T: Randomizer + 'static>(&mut self, rgba: &[u8], width: usize, height: usize, threshold: f64, randomizer: T) -> Result<(), String> {
+        let cells = image::to_cells(rgba, width, height, threshold, self.config.dither)?;
+        let mut optimizer = optimizer::from_config(&self.config, randomizer)?;
+        let (board_width, _) = self.game_of_life.size();
+        let mut layout = Layout::new(&self.config, board_width);
+        let font_size = self.config.font_size;
+        let tiles: Vec<Compound> = Mold::from_bitmap(&cells, font_size, self.config.resampling)
+            .into_iter()
+            .map(|mold| optimizer.optimize(mold))
+            .collect();
+        layout.push_image(&tiles, Mold::bitmap_width(&cells, font_size));
+        self.place(layout);
+        Ok(())
+    }
+
+    // Draws the laid out glyphs on a cleared board.
+    fn place(&mut self, mut layout: Layout) {
+        layout.finish();
+
+        self.game_of_life.clear();
+        let mut placements = Vec::new();
+        for glyph in layout.glyphs {
+            for (x, y, p) in glyph.primes {
+                placements.push((glyph.x as i64 + x, glyph.y as i64 + y, p));
+            }
+            self.game_of_life.allocate(glyph.cells, glyph.x, glyph.y);
+        }
+        self.removed_primes = self.game_of_life.repair(&mut placements);
+        self.text_width = layout.width;
+        self.text_height = layout.height;
+    }
+}
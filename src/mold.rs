@@ -1,91 +1,211 @@
+use std::str::FromStr;
+use bitmap_font::*;
 use cells::*;
 use fonts::*;
+use resample::*;
+use svg::*;
+#[cfg(feature = "truetype")]
+use truetype::*;
 
 pub struct Mold {
     pub font_size: usize,
     pub target: Cells,
 }
 
+// Where the glyphs of the text come from.
+pub trait Typeface {
+    // Bitmap glyphs are scaled up to the font size with `resampling`.
+    fn mold(&self, c: char, font_size: usize, resampling: Resampling) -> Mold;
+    // How far the glyph after `c` starts, in cells.
+    fn advance(&self, c: char, next: Option<char>, font_size: usize) -> usize;
+}
+
+// The glyphs in `fonts.rs`.
+pub struct BuiltIn;
+
+impl Typeface for BuiltIn {
+    fn mold(&self, c: char, font_size: usize, resampling: Resampling) -> Mold {
+        Mold::resampled(glyph(c).unwrap_or(FONT_EMPTY), font_size, resampling)
+    }
+
+    fn advance(&self, c: char, next: Option<char>, font_size: usize) -> usize {
+        glyph(c).unwrap_or(FONT_EMPTY).advance(next, font_size)
+    }
+}
+
+// Reads a BDF or PSF bitmap font, or a TrueType or OpenType font when built
+// with the `truetype` feature.
+pub fn load_typeface(bytes: &[u8]) -> Result<Box<dyn Typeface>, String> {
+    let magic: &[&[u8]] = &[&[0, 1, 0, 0], b"OTTO", b"true", b"ttcf"];
+    if !magic.iter().any(|m| bytes.starts_with(m)) {
+        return Ok(Box::new(BitmapFont::from_bytes(bytes)?));
+    }
+    load_truetype(bytes)
+}
+
+#[cfg(feature = "truetype")]
+fn load_truetype(bytes: &[u8]) -> Result<Box<dyn Typeface>, String> {
+    Ok(Box::new(TrueTypeFont::from_bytes(bytes)?))
+}
+
+#[cfg(not(feature = "truetype"))]
+fn load_truetype(_: &[u8]) -> Result<Box<dyn Typeface>, String> {
+    Err("TrueType and OpenType fonts need the truetype feature".to_string())
+}
+
+// How the letters are drawn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextStyle {
+    Normal,
+    // Primes fill the space around the letter and leave the letter empty.
+    Inverted,
+    // Only the edge of the letter is filled.
+    Outline,
+    // The letter is thickened to the right and down.
+    Bold,
+}
+
+impl FromStr for TextStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<TextStyle, String> {
+        match s.to_lowercase().as_str() {
+            "normal" => Ok(TextStyle::Normal),
+            "inverted" => Ok(TextStyle::Inverted),
+            "outline" => Ok(TextStyle::Outline),
+            "bold" => Ok(TextStyle::Bold),
+            _ => Err(format!("unknown text style: {}", s)),
+        }
+    }
+}
+
+impl TextStyle {
+    // Bold glyphs are wider, so they advance further.
+    pub fn advance(&self, advance: usize, font_size: usize) -> usize {
+        match *self {
+            TextStyle::Bold => advance + stroke(font_size),
+            _ => advance,
+        }
+    }
+}
+
+// Outlines and bold strokes are about half as thick as a pixel of the
+// built-in glyphs, so the outline of a two pixel stem is not filled in.
+fn stroke(font_size: usize) -> usize {
+    (font_size / EM / 2).max(1)
+}
+
 impl Mold {
+    #[cfg(test)]
     pub fn new(font: Font, font_size: usize) -> Self {
-        let mold_scale = font_size as f64 / font.val.len() as f64;
+        Mold::resampled(font, font_size, Resampling::Nearest)
+    }
+
+    // Draws the glyph from the left edge of the mold, with its baseline on the
+    // baseline of the em. The glyph is scaled by font_size / EM, which need
+    // not be a whole number.
+    pub fn resampled(font: Font, font_size: usize, resampling: Resampling) -> Self {
+        let mold_scale = font_size as f64 / EM as f64;
+        let top = (ASCENT.saturating_sub(font.baseline) as f64 * mold_scale).round() as usize;
+        let glyph = Cells::from_font(font);
+        let size_x = (glyph.size_x as f64 * mold_scale).round() as usize;
+        let size_y = (glyph.size_y as f64 * mold_scale).round() as usize;
         let mut cells = Cells::new(font_size, font_size);
-        cells.allocate(Cells::from_font(font), 0, 0, mold_scale);
+        cells.allocate(resample(&glyph, size_x, size_y, resampling), 0, top, 1.0);
         Mold {
             font_size: font_size,
             target: cells,
         }
     }
 
-    pub fn from_char(c: char, font_size: usize) -> Self {
-        match c {
-            'A' => Mold::new(FONT_A, font_size),
-            'B' => Mold::new(FONT_B, font_size),
-            'C' => Mold::new(FONT_C, font_size),
-            'D' => Mold::new(FONT_D, font_size),
-            'E' => Mold::new(FONT_E, font_size),
-            'F' => Mold::new(FONT_F, font_size),
-            'G' => Mold::new(FONT_G, font_size),
-            'H' => Mold::new(FONT_H, font_size),
-            'I' => Mold::new(FONT_I, font_size),
-            'J' => Mold::new(FONT_J, font_size),
-            'K' => Mold::new(FONT_K, font_size),
-            'L' => Mold::new(FONT_L, font_size),
-            'N' => Mold::new(FONT_N, font_size),
-            'M' => Mold::new(FONT_M, font_size),
-            'O' => Mold::new(FONT_O, font_size),
-            'P' => Mold::new(FONT_P, font_size),
-            'Q' => Mold::new(FONT_Q, font_size),
-            'R' => Mold::new(FONT_R, font_size),
-            'S' => Mold::new(FONT_S, font_size),
-            'T' => Mold::new(FONT_T, font_size),
-            'U' => Mold::new(FONT_U, font_size),
-            'V' => Mold::new(FONT_V, font_size),
-            'W' => Mold::new(FONT_W, font_size),
-            'X' => Mold::new(FONT_X, font_size),
-            'Y' => Mold::new(FONT_Y, font_size),
-            'Z' => Mold::new(FONT_Z, font_size),
-            'a' => Mold::new(FONT_CASE_A, font_size),
-            'b' => Mold::new(FONT_CASE_B, font_size),
-            'c' => Mold::new(FONT_CASE_C, font_size),
-            'd' => Mold::new(FONT_CASE_D, font_size),
-            'e' => Mold::new(FONT_CASE_E, font_size),
-            'f' => Mold::new(FONT_CASE_F, font_size),
-            'g' => Mold::new(FONT_CASE_G, font_size),
-            'h' => Mold::new(FONT_CASE_H, font_size),
-            'i' => Mold::new(FONT_CASE_I, font_size),
-            'j' => Mold::new(FONT_CASE_J, font_size),
-            'k' => Mold::new(FONT_CASE_K, font_size),
-            'l' => Mold::new(FONT_CASE_L, font_size),
-            'n' => Mold::new(FONT_CASE_N, font_size),
-            'm' => Mold::new(FONT_CASE_M, font_size),
-            'o' => Mold::new(FONT_CASE_O, font_size),
-            'p' => Mold::new(FONT_CASE_P, font_size),
-            'q' => Mold::new(FONT_CASE_Q, font_size),
-            'r' => Mold::new(FONT_CASE_R, font_size),
-            's' => Mold::new(FONT_CASE_S, font_size),
-            't' => Mold::new(FONT_CASE_T, font_size),
-            'u' => Mold::new(FONT_CASE_U, font_size),
-            'v' => Mold::new(FONT_CASE_V, font_size),
-            'w' => Mold::new(FONT_CASE_W, font_size),
-            'x' => Mold::new(FONT_CASE_X, font_size),
-            'y' => Mold::new(FONT_CASE_Y, font_size),
-            'z' => Mold::new(FONT_CASE_Z, font_size),
-            '1' => Mold::new(FONT_1, font_size),
-            '2' => Mold::new(FONT_2, font_size),
-            '3' => Mold::new(FONT_3, font_size),
-            '4' => Mold::new(FONT_4, font_size),
-            '5' => Mold::new(FONT_5, font_size),
-            '6' => Mold::new(FONT_6, font_size),
-            '7' => Mold::new(FONT_7, font_size),
-            '8' => Mold::new(FONT_8, font_size),
-            '9' => Mold::new(FONT_9, font_size),
-            '0' => Mold::new(FONT_0, font_size),
-            _ => Mold::new(FONT_EMPTY, font_size),
+    // Scales a bitmap of any size onto a `font_size` square.
+    pub fn from_cells(cells: &Cells, font_size: usize, resampling: Resampling) -> Self {
+        Mold {
+            font_size: font_size,
+            target: resample(cells, font_size, font_size, resampling),
+        }
+    }
+
+    // How wide a bitmap is once scaled to the font size in height.
+    pub fn bitmap_width(cells: &Cells, font_size: usize) -> usize {
+        if cells.size_y == 0 || font_size == 0 {
+            return 0;
+        }
+        ((cells.size_x * font_size) as f64 / cells.size_y as f64).round().max(1.0) as usize
+    }
+
+    // Scales a bitmap, such as an image, to the font size in height and cuts
+    // it into `font_size` squares from left to right.
+    pub fn from_bitmap(cells: &Cells, font_size: usize, resampling: Resampling) -> Vec<Self> {
+        let size_x = Mold::bitmap_width(cells, font_size);
+        if size_x == 0 {
+            return Vec::new();
+        }
+        let scaled = resample(cells, size_x, font_size, resampling);
+        (0..size_x.div_ceil(font_size))
+            .map(|i| {
+                let mut target = Cells::new(font_size, font_size);
+                for x in 0..font_size.min(size_x - i * font_size) {
+                    for y in 0..font_size {
+                        if scaled.is_alive(i * font_size + x, y) {
+                            target.make_alive(x, y);
+                        }
+                    }
+                }
+                Mold {
+                    font_size: font_size,
+                    target: target,
+                }
+            })
+            .collect()
+    }
+
+    // Restyles the target. An inverted glyph is filled over `width` columns,
+    // up to where the next glyph starts.
+    pub fn styled(self, style: TextStyle, width: usize) -> Self {
+        let font_size = self.font_size;
+        let target = match style {
+            TextStyle::Normal => self.target,
+            TextStyle::Inverted => {
+                let mut inverted = Cells::new(font_size, font_size);
+                for x in 0..width.min(font_size) {
+                    for y in 0..font_size {
+                        if !self.target.is_alive(x, y) {
+                            inverted.make_alive(x, y);
+                        }
+                    }
+                }
+                inverted
+            },
+            TextStyle::Outline => {
+                let inside = (0..stroke(font_size)).fold(self.target.clone(), |cells, _| cells.erode());
+                self.target.difference(&inside)
+            },
+            TextStyle::Bold => (0..stroke(font_size)).fold(self.target, |cells, _| cells.dilate()),
+        };
+        Mold {
+            font_size: font_size,
+            target: target,
+        }
+    }
+
+    pub fn from_svg(svg: &Svg, font_size: usize) -> Self {
+        Mold {
+            font_size: font_size,
+            target: svg.rasterize(font_size),
         }
     }
 
+    // Characters without a built-in glyph are left blank.
+    #[cfg(test)]
+    pub fn from_char(c: char, font_size: usize) -> Self {
+        Mold::new(glyph(c).unwrap_or(FONT_EMPTY), font_size)
+    }
+
     pub fn intersect(&self, cells: &Cells) -> u64 {
+        if self.same_size(cells) {
+            return self.count_words(cells, |target, alive| target & alive);
+        }
         let mut count = 0;
 
         for x in 0..self.target.size_x {
@@ -100,6 +220,9 @@ impl Mold {
     }
 
     pub fn difference(&self, cells: &Cells) -> u64 {
+        if self.same_size(cells) {
+            return self.count_words(cells, |target, alive| !target & alive);
+        }
         let mut count = 0;
 
         for x in 0..self.target.size_x {
@@ -112,6 +235,18 @@ impl Mold {
 
         count
     }
+
+    fn same_size(&self, cells: &Cells) -> bool {
+        self.target.size_x == cells.size_x && self.target.size_y == cells.size_y
+    }
+
+    // Counts the cells selected by `f` a whole word of cells at a time.
+    fn count_words<F: Fn(u64, u64) -> u64>(&self, cells: &Cells, f: F) -> u64 {
+        self.target.rows.iter()
+            .zip(cells.rows.iter())
+            .map(|(&target, &alive)| f(target, alive).count_ones() as u64)
+            .sum()
+    }
 }
 
 #[test]
@@ -128,7 +263,10 @@ fn test_intersect() {
             &[0, 0, 1, 1, 0, 0, 0, 0, 0, 0],
             &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
             &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        ]
+        ],
+        width: 10,
+        baseline: 8,
+        kerning: &[],
     };
     let test_font2 = Font {
         val : &[
@@ -142,7 +280,10 @@ fn test_intersect() {
             &[0, 0, 1, 0, 1, 0, 0, 0, 0, 0],
             &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
             &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        ]
+        ],
+        width: 10,
+        baseline: 8,
+        kerning: &[],
     };
 
     let mold = Mold::new(test_font, 20);
@@ -166,7 +307,10 @@ fn test_difference() {
             &[0, 0, 1, 1, 0, 0, 0, 0, 0, 0],
             &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
             &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        ]
+        ],
+        width: 10,
+        baseline: 8,
+        kerning: &[],
     };
     let test_font2 = Font {
         val : &[
@@ -180,7 +324,10 @@ fn test_difference() {
             &[0, 0, 1, 0, 1, 0, 0, 0, 0, 0],
             &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
             &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
-        ]
+        ],
+        width: 10,
+        baseline: 8,
+        kerning: &[],
     };
 
     let mold = Mold::new(test_font, 20);
@@ -189,3 +336,121 @@ fn test_difference() {
 
     assert_eq!(24, mold.difference(&cells));
 }
+
+#[test]
+fn test_from_cells() {
+    let mut cells = Cells::new(4, 4);
+    cells.make_alive(1, 2);
+    let mold = Mold::from_cells(&cells, 6, Resampling::Nearest);
+
+    assert_eq!(6, mold.font_size);
+    assert_eq!(2, mold.target.count_alive());
+    assert!(mold.target.is_alive(1, 3) && mold.target.is_alive(2, 3));
+
+    let mold = Mold::from_cells(&Mold::new(FONT_A, 10).target, 20, Resampling::Nearest);
+    assert_eq!(Mold::new(FONT_A, 20).target, mold.target);
+}
+
+#[test]
+fn test_from_bitmap() {
+    let cells = Cells::from_vec(vec![
+        vec![1, 0, 0, 0, 0],
+        vec![0, 0, 0, 0, 1],
+    ]);
+    let molds = Mold::from_bitmap(&cells, 4, Resampling::Nearest);
+
+    assert_eq!(3, molds.len());
+    assert!(molds.iter().all(|m| m.font_size == 4 && m.target.size_x == 4));
+    assert_eq!(4, molds[0].target.count_alive());
+    assert!(molds[0].target.is_alive(1, 1) && !molds[0].target.is_alive(2, 0));
+    assert_eq!(0, molds[1].target.count_alive());
+    assert_eq!(4, molds[2].target.count_alive());
+    assert!(molds[2].target.is_alive(1, 3) && !molds[2].target.is_alive(2, 3));
+}
+
+#[test]
+fn test_from_svg() {
+    use raster::FillRule;
+
+    // An arrow pointing right, half as tall as it is wide.
+    let svg = Svg::parse("M0 2h6V0l4 4-4 4V6H0z", FillRule::NonZero).unwrap();
+    let mold = Mold::from_svg(&svg, 20);
+
+    assert_eq!(20, mold.font_size);
+    assert_eq!(20, svg.width(20));
+    assert_eq!(Some(0), mold.target.columns().map(|(left, _)| left));
+    assert!(mold.target.is_alive(1, 9) && !mold.target.is_alive(1, 3));
+    assert!(mold.target.is_alive(18, 9) && !mold.target.is_alive(18, 2));
+}
+
+#[test]
+fn test_styled() {
+    let mold = || Mold::new(FONT_CASE_I, 20);
+    let normal = mold().target;
+
+    let inverted = mold().styled(TextStyle::Inverted, 6).target;
+    assert_eq!(6 * 20 - normal.count_alive(), inverted.count_alive());
+    assert!(!inverted.is_alive(0, 12) && inverted.is_alive(0, 7) && inverted.is_alive(5, 12));
+    assert!(!inverted.is_alive(6, 12));
+
+    // The stem of the i is 4 cells wide, so its outline is the whole stem
+    // minus the 2 cells in the middle of each row.
+    let outline = mold().styled(TextStyle::Outline, 6).target;
+    assert!(outline.is_alive(0, 12) && outline.is_alive(3, 12));
+    assert!(!outline.is_alive(1, 12) && !outline.is_alive(2, 12));
+
+    let bold = mold().styled(TextStyle::Bold, 6).target;
+    assert_eq!(Some((0, 4)), bold.columns());
+    assert_eq!(5, TextStyle::Bold.advance(4, 20));
+    assert_eq!(4, TextStyle::Outline.advance(4, 20));
+    assert_eq!(normal, mold().styled(TextStyle::Normal, 6).target);
+}
+
+#[test]
+fn test_text_style_from_str() {
+    assert_eq!(Ok(TextStyle::Inverted), "inverted".parse());
+    assert_eq!(Ok(TextStyle::Bold), "Bold".parse());
+    assert!("italic".parse::<TextStyle>().is_err());
+}
+
+#[test]
+fn test_from_char() {
+    assert_eq!(Mold::new(FONT_CASE_G, 10).target, Mold::from_char('g', 10).target);
+    assert_eq!(0, Mold::from_char(' ', 10).target.count_alive());
+    assert_eq!(0, Mold::from_char('\u{3042}', 10).target.count_alive());
+    for c in "!,.?@&é~ÿß¿".chars() {
+        assert!(Mold::from_char(c, 10).target.count_alive() > 0, "{}", c);
+    }
+    assert!(Mold::from_char('é', 10).target != Mold::from_char('e', 10).target);
+}
+
+#[test]
+fn test_new_draws_from_the_left() {
+    let mold = Mold::new(FONT_CASE_I, 20);
+
+    assert_eq!(Some((0, 3)), mold.target.columns());
+    assert_eq!(4, FONT_CASE_I.advance(None, 20));
+}
+
+#[test]
+fn test_fractional_scale() {
+    // 45 is 4.5 times the em, not 4 times as it used to be.
+    let mold = Mold::new(FONT_CASE_I, 45);
+    assert_eq!(Some((0, 8)), mold.target.columns());
+
+    let nearest = Mold::resampled(FONT_CASE_O, 45, Resampling::Nearest).target;
+    for &resampling in [Resampling::Bilinear, Resampling::Scale2x].iter() {
+        let smooth = Mold::resampled(FONT_CASE_O, 45, resampling).target;
+        assert_eq!(nearest.columns(), smooth.columns());
+        assert!(smooth != nearest);
+    }
+}
+
+#[test]
+fn test_load_typeface() {
+    assert!(load_typeface(b"STARTFONT 2.1\nFONTBOUNDINGBOX 4 6 0 -1\nENDFONT\n").is_ok());
+    assert!(load_typeface(b"hello").is_err());
+    assert!(load_typeface(b"OTTO").is_err());
+    assert_eq!(16, BuiltIn.advance('A', Some('B'), 20));
+    assert_eq!(14, BuiltIn.advance('A', Some('V'), 20));
+}
@@ -1,47 +1,125 @@
+use std::str::FromStr;
+use annealing::*;
+use config::*;
+use exact::*;
+use fitness;
+use fitness::*;
+use genetic::*;
 use mold::*;
 use cells::*;
+#[cfg(test)]
+use evolve::*;
 use compound::*;
 use primes::*;
 use randomizer::*;
+use rule::*;
 
 pub trait Optimizer {
     fn optimize(&mut self, mold: Mold) -> Compound;
+    fn set_fitness(&mut self, fitness: Box<dyn Fitness>);
+    fn set_primes(&mut self, primes: Vec<Prime<'static>>);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OptimizerKind {
+    GradientDescent,
+    SimulatedAnnealing,
+    BranchAndBound,
+    Genetic,
+}
+
+impl FromStr for OptimizerKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<OptimizerKind, String> {
+        match s.to_lowercase().as_str() {
+            "gradient" => Ok(OptimizerKind::GradientDescent),
+            "annealing" => Ok(OptimizerKind::SimulatedAnnealing),
+            "exact" => Ok(OptimizerKind::BranchAndBound),
+            "genetic" => Ok(OptimizerKind::Genetic),
+            _ => Err(format!("unknown optimizer: {}", s)),
+        }
+    }
+}
+
+pub fn from_config<T: Randomizer + 'static>(config: &Config, randomizer: T) -> Result<Box<dyn Optimizer>, String> {
+    let rule = &config.rule;
+    let mut optimizer: Box<dyn Optimizer> = match config.optimizer {
+        OptimizerKind::GradientDescent => {
+            Box::new(GradientDescent::new(randomizer, config.gradient_passes, rule)?)
+        },
+        OptimizerKind::SimulatedAnnealing => {
+            Box::new(SimulatedAnnealing::new(randomizer, config.annealing_steps, rule)?)
+        },
+        OptimizerKind::BranchAndBound => {
+            Box::new(BranchAndBound::new(config.node_limit, rule)?)
+        },
+        OptimizerKind::Genetic => {
+            Box::new(Genetic::new(randomizer, config.population_size, config.generations, rule)?)
+        },
+    };
+    optimizer.set_fitness(fitness::from_config(config));
+    if !config.primes.is_empty() {
+        optimizer.set_primes(stable_primes_with(rule, &config.primes)?);
+    }
+    Ok(optimizer)
 }
 
 pub struct GradientDescent<T: Randomizer> {
     pub randomizer: T,
     pub n: usize,
+    pub primes: Vec<Prime<'static>>,
+    pub fitness: Box<dyn Fitness>,
 }
 
 impl<T: Randomizer> Optimizer for GradientDescent<T> {
     fn optimize(&mut self, mold: Mold) -> Compound {
         let mut pattern = Compound::new(mold.font_size);
         let mut occupied = Cells::new(mold.font_size, mold.font_size);
-        let mut score;
 
         self.add_primes(&mold, &mut pattern, &mut occupied);
-        self.allocate_compound(&mut occupied, &pattern);
-        score = self.evaluate(&mold, &pattern);
+        let mut score = self.evaluate(&mold, &pattern);
         for _ in 0..self.n {
-            let mut tmp = Compound {
-                font_size: pattern.font_size,
-                pattern: pattern.pattern.clone(),
-            };
-            self.remove_primes(&mold, &mut tmp);
-            occupied.clear();
-            self.allocate_compound(&mut occupied, &tmp);
-            self.add_primes(&mold, &mut tmp, &mut occupied);
-            let mut tmp_score = self.evaluate(&mold, &tmp);
-            if score < tmp_score {
-                pattern = tmp;
-                score = tmp_score;
-            }
+            let (improved, improved_score) = self.improve(&mold, pattern, score);
+            pattern = improved;
+            score = improved_score;
         }
         pattern
     }
+
+    fn set_fitness(&mut self, fitness: Box<dyn Fitness>) {
+        self.fitness = fitness;
+    }
+
+    fn set_primes(&mut self, primes: Vec<Prime<'static>>) {
+        self.primes = primes;
+    }
 }
 
 impl<T: Randomizer> GradientDescent<T> {
+    pub fn new(randomizer: T, n: usize, rule: &Rule) -> Result<Self, String> {
+        Ok(GradientDescent {
+            randomizer: randomizer,
+            n: n,
+            primes: stable_primes(rule)?,
+            fitness: default_fitness(),
+        })
+    }
+
+    fn improve(&mut self, mold: &Mold, pattern: Compound, score: f64) -> (Compound, f64) {
+        let mut tmp = pattern.clone();
+        let mut occupied = Cells::new(mold.font_size, mold.font_size);
+        self.remove_primes(mold, &mut tmp);
+        self.allocate_compound(&mut occupied, &tmp);
+        self.add_primes(mold, &mut tmp, &mut occupied);
+        let tmp_score = self.evaluate(mold, &tmp);
+        if score < tmp_score {
+            (tmp, tmp_score)
+        } else {
+            (pattern, score)
+        }
+    }
+
     fn remove_primes(&mut self, mold: &Mold, pattern: &mut Compound) {
         for x in 0..mold.font_size {
             for y in 0..mold.font_size {
@@ -58,10 +136,11 @@ impl<T: Randomizer> GradientDescent<T> {
         for x in 0..mold.font_size {
             for y in 0..mold.font_size {
                 if !mold.target.is_allocatable(x, y, 2) && self.randomizer.random_number() < 0.05 {
-                    let p = self.get_random_prime();
-                    if occupied.is_allocatable(x, y, p.exclusive_size) {
-                        pattern.set(p, x, y);
+                    let p = self.get_fitting_prime(mold, x, y);
+                    let inside = x + p.exclusive_size <= mold.font_size && y + p.exclusive_size <= mold.font_size;
+                    if inside && occupied.is_allocatable(x, y, p.exclusive_size) {
                         occupied.make_alive_square(x, y, p.exclusive_size);
+                        pattern.set(p, x, y);
                     }
                 }
             }
@@ -78,23 +157,257 @@ impl<T: Randomizer> GradientDescent<T> {
         }
     }
 
-    fn get_random_prime(&mut self) -> Prime<'static> {
-        let n = (self.randomizer.random_number() * 7.0) as usize;
-        match n {
-            0 => BLOCK,
-            1 => TUB,
-            2 => BEEHIVE,
-            3 => BLINKER,
-            4 => BEACON,
-            5 => CLOCK,
-            _ => TOAD,
-        }
+    fn get_fitting_prime(&mut self, mold: &Mold, x: usize, y: usize) -> Prime<'static> {
+        fitting_prime(&mut self.randomizer, &self.primes, mold, |_| (x, y))
     }
 
     fn evaluate(&self, mold: &Mold, pattern: &Compound) -> f64 {
-        let cells = pattern.to_cells();
-        let a = mold.intersect(&cells);
-        let b = mold.difference(&cells);
-        a as f64 - (b as f64) * 0.5
+        self.fitness.score(mold, pattern)
+    }
+}
+
+pub fn random_prime<T: Randomizer>(randomizer: &mut T, primes: &[Prime<'static>]) -> Prime<'static> {
+    let n = (randomizer.random_number() * primes.len() as f64) as usize;
+    primes[n.min(primes.len() - 1)].clone()
+}
+
+// Picks a prime with a probability proportional to how well it fits the mold
+// at the anchor `anchor` gives for it, or uniformly if none fits at all.
+pub fn fitting_prime<T, F>(randomizer: &mut T, primes: &[Prime<'static>], mold: &Mold, anchor: F) -> Prime<'static>
+    where T: Randomizer, F: Fn(&Prime<'static>) -> (usize, usize)
+{
+    let weights: Vec<f64> = primes.iter()
+        .map(|p| {
+            let (x, y) = anchor(p);
+            local_fit(mold, p, x, y).max(0.0)
+        })
+        .collect();
+    let total: f64 = weights.iter().sum();
+    if total <= 0.0 {
+        return random_prime(randomizer, primes);
+    }
+
+    let mut r = randomizer.random_number() * total;
+    for (i, &w) in weights.iter().enumerate() {
+        if r < w {
+            return primes[i].clone();
+        }
+        r -= w;
+    }
+    let last = weights.iter().rposition(|&w| w > 0.0).unwrap();
+    primes[last].clone()
+}
+
+// Picks a fitting prime to cover the target cell (tx, ty), at a random
+// position of its box, and returns it with its anchor.
+pub fn place_near<T: Randomizer>(randomizer: &mut T, primes: &[Prime<'static>], mold: &Mold, tx: usize, ty: usize) -> (Prime<'static>, usize, usize) {
+    let (fx, fy) = (randomizer.random_number(), randomizer.random_number());
+    let anchor = |p: &Prime<'static>| {
+        let size = p.exclusive_size as f64;
+        (tx.saturating_sub((fx * size) as usize), ty.saturating_sub((fy * size) as usize))
+    };
+    let p = fitting_prime(randomizer, primes, mold, anchor);
+    let (x, y) = anchor(&p);
+    (p, x, y)
+}
+
+// How well the prime drawn at (x, y) matches the mold, averaged over its
+// phases. Cells beyond the mold count as strays.
+pub fn local_fit(mold: &Mold, prime: &Prime, x: usize, y: usize) -> f64 {
+    let size = mold.font_size;
+    let mut score = 0.0;
+    for phase in prime.phases.iter() {
+        for x2 in 0..prime.exclusive_size {
+            for y2 in 0..prime.exclusive_size {
+                if phase[x2][y2] == 0 {
+                    continue;
+                }
+                let (cx, cy) = (x + x2, y + y2);
+                if cx < size && cy < size && mold.target.is_alive(cx, cy) {
+                    score += 1.0;
+                } else {
+                    score -= 0.5;
+                }
+            }
+        }
+    }
+    score / prime.phases.len() as f64
+}
+
+// Scores a compound with the default fitness.
+#[cfg(test)]
+pub fn evaluate(mold: &Mold, pattern: &Compound) -> f64 {
+    default_fitness().score(mold, pattern)
+}
+
+// The cells of the mold that should be alive.
+pub fn targets(mold: &Mold) -> Vec<(usize, usize)> {
+    let mut targets = Vec::new();
+    for x in 0..mold.target.size_x {
+        for y in 0..mold.target.size_y {
+            if mold.target.is_alive(x, y) {
+                targets.push((x, y));
+            }
+        }
+    }
+    targets
+}
+
+#[test]
+fn test_optimize_is_reproducible() {
+    let optimize = |seed| {
+        let mut optimizer = GradientDescent::new(SplitMix::from_seed(seed), 5, &Rule::conway()).unwrap();
+        optimizer.optimize(Mold::from_char('R', 30)).pattern
+    };
+
+    assert_eq!(optimize(7), optimize(7));
+    assert_ne!(optimize(7), optimize(8));
+}
+
+#[cfg(test)]
+struct Fixed {
+    numbers: Vec<f64>,
+    index: usize,
+}
+
+#[cfg(test)]
+impl Randomizer for Fixed {
+    fn new() -> Self {
+        Fixed {
+            numbers: vec![0.0],
+            index: 0,
+        }
+    }
+
+    fn random_number(&mut self) -> f64 {
+        let n = self.numbers[self.index % self.numbers.len()];
+        self.index += 1;
+        n
+    }
+}
+
+#[cfg(test)]
+fn optimized_molds() -> Vec<(Mold, Compound)> {
+    let mut results = Vec::new();
+    for seed in 0..8 {
+        for &font_size in &[20, 30, 45] {
+            for c in "AgW8x".chars() {
+                let mut optimizer = GradientDescent::new(SplitMix::from_seed(seed), 5, &Rule::conway()).unwrap();
+                let compound = optimizer.optimize(Mold::from_char(c, font_size));
+                results.push((Mold::from_char(c, font_size), compound));
+            }
+        }
+    }
+    results
+}
+
+#[test]
+fn test_add_primes_with_fixed_randomizer() {
+    let mut target = Cells::new(10, 10);
+    target.make_alive_square(0, 0, 10);
+    let mold = Mold {
+        font_size: 10,
+        target: target,
+    };
+    let mut optimizer = GradientDescent::new(Fixed::new(), 5, &Rule::conway()).unwrap();
+    let mut pattern = Compound::new(10);
+    let mut occupied = Cells::new(10, 10);
+    optimizer.add_primes(&mold, &mut pattern, &mut occupied);
+
+    assert_eq!(vec![(0, 0, BLOCK), (0, 4, BLOCK), (4, 0, BLOCK), (4, 4, BLOCK)], pattern.placements());
+}
+
+#[test]
+fn test_local_fit() {
+    let mut target = Cells::new(10, 10);
+    target.make_alive_square(0, 0, 10);
+    let full = Mold {
+        font_size: 10,
+        target: target,
+    };
+
+    assert_eq!(4.0, local_fit(&full, &BLOCK, 0, 0));
+    assert_eq!(3.0, local_fit(&full, &BLINKER, 5, 5));
+    assert_eq!(1.5, local_fit(&full, &BLINKER, 7, 7));
+    assert_eq!(-2.0, local_fit(&Mold::from_char(' ', 10), &BLOCK, 0, 0));
+}
+
+#[test]
+fn test_fitting_prime_picks_primes_that_fit() {
+    let mut target = Cells::new(10, 10);
+    target.make_alive_square(1, 1, 2);
+    let mold = Mold {
+        font_size: 10,
+        target: target,
+    };
+    let mut randomizer = SplitMix::from_seed(0);
+    let primes = stable_primes(&Rule::conway()).unwrap();
+
+    for _ in 0..100 {
+        let p = fitting_prime(&mut randomizer, &primes, &mold, |_| (0, 0));
+        assert!(local_fit(&mold, &p, 0, 0) > 0.0, "{:?}", p.prime_type);
+    }
+}
+
+#[test]
+fn test_optimize_places_primes_without_overlap() {
+    for (_, compound) in optimized_molds() {
+        let placements = compound.placements();
+        for (i, &(x1, y1, ref p1)) in placements.iter().enumerate() {
+            for &(x2, y2, ref p2) in placements.iter().skip(i + 1) {
+                let apart_x = x1 + p1.exclusive_size <= x2 || x2 + p2.exclusive_size <= x1;
+                let apart_y = y1 + p1.exclusive_size <= y2 || y2 + p2.exclusive_size <= y1;
+                assert!(apart_x || apart_y, "{:?} at ({}, {}) overlaps {:?} at ({}, {})", p1.prime_type, x1, y1, p2.prime_type, x2, y2);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_optimize_keeps_primes_inside_mold() {
+    for (mold, compound) in optimized_molds() {
+        for (x, y, p) in compound.placements() {
+            assert!(x + p.exclusive_size <= mold.font_size && y + p.exclusive_size <= mold.font_size);
+        }
+        let cells = compound.to_cells();
+        assert_eq!(mold.font_size, cells.size_x);
+        assert_eq!(mold.font_size, cells.size_y);
+    }
+}
+
+#[test]
+fn test_improve_never_decreases_score() {
+    for seed in 0..8 {
+        let mold = Mold::from_char('B', 30);
+        let mut optimizer = GradientDescent::new(SplitMix::from_seed(seed), 5, &Rule::conway()).unwrap();
+        let mut pattern = Compound::new(mold.font_size);
+        let mut occupied = Cells::new(mold.font_size, mold.font_size);
+        optimizer.add_primes(&mold, &mut pattern, &mut occupied);
+        let mut score = optimizer.evaluate(&mold, &pattern);
+
+        for _ in 0..20 {
+            let (improved, improved_score) = optimizer.improve(&mold, pattern, score);
+            assert!(improved_score >= score);
+            assert_eq!(improved_score, optimizer.evaluate(&mold, &improved));
+            pattern = improved;
+            score = improved_score;
+        }
+    }
+}
+
+#[test]
+fn test_optimize_is_still_or_periodic() {
+    let rule = Rule::conway();
+    for (_, compound) in optimized_molds() {
+        let mut initial = compound.to_cells();
+        initial.topology = Topology::Dead;
+        let period = compound.period();
+
+        let mut cells = initial.clone();
+        for _ in 0..period {
+            cells = next(&cells, &rule);
+        }
+
+        assert_eq!(initial, cells);
     }
 }
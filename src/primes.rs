@@ -1,5 +1,15 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::ops::Index;
+use std::rc::Rc;
+use cells::*;
+use evolve::*;
+use rle;
+use rule::*;
+
 #[repr(u8)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum PrimeType {
     Empty = 0,
     Block = 1,
@@ -9,159 +19,1358 @@ pub enum PrimeType {
     Beacon = 5,
     Clock = 6,
     Toad = 7,
+    Loaf = 8,
+    Boat = 9,
+    Ship = 10,
+    Pond = 11,
+    LongBoat = 12,
+    Snake = 13,
+    Pulsar = 14,
+    Pentadecathlon = 15,
+    FigureEight = 16,
+    Custom = 17,
+}
+
+// The columns of a square mask. Built-in primes point at constants, while
+// primes made at runtime share their masks and free them when dropped.
+#[derive(Clone, Debug)]
+pub enum Mask<'a> {
+    Static(&'a [&'a [u8]]),
+    Shared(Rc<[Vec<u8>]>),
+}
+
+impl<'a> Mask<'a> {
+    pub fn len(&self) -> usize {
+        match *self {
+            Mask::Static(columns) => columns.len(),
+            Mask::Shared(ref columns) => columns.len(),
+        }
+    }
+}
+
+impl<'a> Index<usize> for Mask<'a> {
+    type Output = [u8];
+
+    fn index(&self, x: usize) -> &[u8] {
+        match *self {
+            Mask::Static(columns) => columns[x],
+            Mask::Shared(ref columns) => &columns[x],
+        }
+    }
+}
+
+impl<'a> From<Vec<Vec<u8>>> for Mask<'a> {
+    fn from(columns: Vec<Vec<u8>>) -> Mask<'a> {
+        Mask::Shared(columns.into())
+    }
+}
+
+// Masks are equal when their cells are, wherever they are stored.
+impl<'a> PartialEq for Mask<'a> {
+    fn eq(&self, other: &Mask<'a>) -> bool {
+        self.len() == other.len() && (0..self.len()).all(|x| self[x] == other[x])
+    }
+}
+
+impl<'a> Eq for Mask<'a> {}
+
+impl<'a> Hash for Mask<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for x in 0..self.len() {
+            self[x].hash(state);
+        }
+    }
+}
+
+// The masks of every generation of a prime, stored like `Mask`.
+#[derive(Clone, Debug)]
+pub enum Phases<'a> {
+    Static(&'a [&'a [&'a [u8]]]),
+    Shared(Rc<[Mask<'a>]>),
+}
+
+impl<'a> Phases<'a> {
+    pub fn len(&self) -> usize {
+        match *self {
+            Phases::Static(phases) => phases.len(),
+            Phases::Shared(ref phases) => phases.len(),
+        }
+    }
+
+    pub fn get(&self, n: usize) -> Mask<'a> {
+        match *self {
+            Phases::Static(phases) => Mask::Static(phases[n]),
+            Phases::Shared(ref phases) => phases[n].clone(),
+        }
+    }
+
+    pub fn iter<'b>(&'b self) -> impl Iterator<Item = Mask<'a>> + 'b {
+        (0..self.len()).map(move |n| self.get(n))
+    }
+}
+
+impl<'a> From<Vec<Mask<'a>>> for Phases<'a> {
+    fn from(phases: Vec<Mask<'a>>) -> Phases<'a> {
+        Phases::Shared(phases.into())
+    }
+}
+
+impl<'a> PartialEq for Phases<'a> {
+    fn eq(&self, other: &Phases<'a>) -> bool {
+        self.len() == other.len() && self.iter().zip(other.iter()).all(|(a, b)| a == b)
+    }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+impl<'a> Eq for Phases<'a> {}
+
+impl<'a> Hash for Phases<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for mask in self.iter() {
+            mask.hash(state);
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Prime<'a> {
     pub prime_type: PrimeType,
     pub exclusive_size: usize,
-    pub occupied: &'a[&'a[u8]],
-    pub prime: &'a[&'a[u8]],
+    pub period: usize,
+    pub occupied: Mask<'a>,
+    pub phases: Phases<'a>,
+}
+
+impl<'a> Prime<'a> {
+    // The mask of the n-th generation, counting from the initial phase.
+    pub fn phase(&self, n: usize) -> Mask<'a> {
+        self.phases.get(n % self.phases.len())
+    }
+
+    // Whether the prime goes through its phases under `rule` without growing
+    // out of its exclusive area.
+    pub fn is_stable(&self, rule: &Rule) -> bool {
+        let size = self.exclusive_size + 2;
+        let mut cells = Cells::new(size, size);
+        cells.allocate_prime(self, 1, 1);
+
+        for n in 1..self.period + 1 {
+            cells = next(&cells, rule);
+            let mut expected = Cells::new(size, size);
+            expected.allocate_phase(self, n, 1, 1);
+            if cells != expected {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+// The symmetries of the square, applied to the exclusive area of a prime.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Transform {
+    Identity,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+    MirrorX,
+    MirrorY,
+    Transpose,
+    AntiTranspose,
+}
+
+pub const TRANSFORMS: &[Transform] = &[
+    Transform::Identity, Transform::Rotate90, Transform::Rotate180, Transform::Rotate270,
+    Transform::MirrorX, Transform::MirrorY, Transform::Transpose, Transform::AntiTranspose,
+];
+
+impl Transform {
+    // Where the cell at (x, y) of a box of the given size ends up. Rotations
+    // are clockwise with y pointing down.
+    pub fn apply(&self, x: usize, y: usize, size: usize) -> (usize, usize) {
+        let n = size - 1;
+        match *self {
+            Transform::Identity => (x, y),
+            Transform::Rotate90 => (n - y, x),
+            Transform::Rotate180 => (n - x, n - y),
+            Transform::Rotate270 => (y, n - x),
+            Transform::MirrorX => (n - x, y),
+            Transform::MirrorY => (x, n - y),
+            Transform::Transpose => (y, x),
+            Transform::AntiTranspose => (n - y, n - x),
+        }
+    }
+}
+
+thread_local! {
+    // The orientations of the built-in primes, which are worked out again for
+    // every optimizer otherwise. Custom primes are not cached, so that their
+    // masks are freed with them.
+    static TRANSFORMED: RefCell<HashMap<(Prime<'static>, Transform), Prime<'static>>> =
+        RefCell::new(HashMap::new());
+}
+
+// How long a custom pattern may take to return to its first phase, and how
+// far it may grow beyond its initial bounding box in the meantime.
+const MAX_PERIOD: usize = 60;
+const MAX_GROWTH: usize = 8;
+// Patterns wider or taller than this, in cells, are rejected rather than
+// simulated.
+const MAX_PATTERN_SIZE: usize = 256;
+
+impl Prime<'static> {
+    // Reads a still life or oscillator from RLE or plaintext and works out its
+    // period and exclusive area by simulating it under `rule`.
+    pub fn from_pattern(s: &str, rule: &Rule) -> Result<Prime<'static>, String> {
+        let alive = rle::parse(s)?;
+        let width = alive.iter().map(|&(x, _)| x).max().unwrap() + 1;
+        let height = alive.iter().map(|&(_, y)| y).max().unwrap() + 1;
+        if width > MAX_PATTERN_SIZE || height > MAX_PATTERN_SIZE {
+            return Err(format!("the pattern is larger than {0}x{0} cells", MAX_PATTERN_SIZE));
+        }
+        let mut cells = Cells::new(width + 2 * MAX_GROWTH, height + 2 * MAX_GROWTH);
+        cells.topology = Topology::Dead;
+        for &(x, y) in &alive {
+            cells.make_alive(x + MAX_GROWTH, y + MAX_GROWTH);
+        }
+
+        let mut generations = vec![cells];
+        loop {
+            let cells = next(&generations[generations.len() - 1], rule);
+            if cells == generations[0] {
+                break;
+            }
+            let grown = bounds(&cells).is_none_or(|(x0, y0, x1, y1)| {
+                x0 == 0 || y0 == 0 || x1 == cells.size_x - 1 || y1 == cells.size_y - 1
+            });
+            if grown || generations.len() == MAX_PERIOD {
+                return Err(format!("the pattern is not a still life or oscillator under {}", rule));
+            }
+            generations.push(cells);
+        }
+
+        // The union of all phases, centred in a square with a dead border.
+        let (mut x0, mut y0, mut x1, mut y1) = (usize::MAX, usize::MAX, 0, 0);
+        for cells in &generations {
+            let (ax, ay, bx, by) = bounds(cells).unwrap();
+            x0 = x0.min(ax);
+            y0 = y0.min(ay);
+            x1 = x1.max(bx);
+            y1 = y1.max(by);
+        }
+        let (width, height) = (x1 - x0 + 1, y1 - y0 + 1);
+        let size = width.max(height) + 2;
+        let left = x0 as i64 - 1 - (size - 2 - width) as i64 / 2;
+        let top = y0 as i64 - 1 - (size - 2 - height) as i64 / 2;
+
+        let mut occupied = vec![vec![0; size]; size];
+        let mut phases = Vec::new();
+        for cells in &generations {
+            let mut mask = vec![vec![0; size]; size];
+            for x in 0..size {
+                for y in 0..size {
+                    if let Some((cx, cy)) = cells.locate(left + x as i64, top + y as i64) {
+                        if cells.is_alive(cx, cy) {
+                            mask[x][y] = 1;
+                            occupied[x][y] = 1;
+                        }
+                    }
+                }
+            }
+            phases.push(Mask::from(mask));
+        }
+
+        Ok(Prime {
+            prime_type: PrimeType::Custom,
+            exclusive_size: size,
+            period: generations.len(),
+            occupied: Mask::from(occupied),
+            phases: Phases::from(phases),
+        })
+    }
+
+    pub fn transform(&self, transform: Transform) -> Prime<'static> {
+        if transform == Transform::Identity {
+            return self.clone();
+        }
+        let cached = self.prime_type != PrimeType::Custom;
+        let key = (self.clone(), transform);
+        if cached {
+            if let Some(p) = TRANSFORMED.with(|t| t.borrow().get(&key).cloned()) {
+                return p;
+            }
+        }
+
+        let size = self.exclusive_size;
+        let phases: Vec<Mask<'static>> = self.phases.iter()
+            .map(|phase| transform_mask(&phase, transform, size))
+            .collect();
+        let p = Prime {
+            prime_type: self.prime_type,
+            exclusive_size: size,
+            period: self.period,
+            occupied: transform_mask(&self.occupied, transform, size),
+            phases: Phases::from(phases),
+        };
+        if cached {
+            TRANSFORMED.with(|t| t.borrow_mut().insert(key, p.clone()));
+        }
+        p
+    }
+
+    // Every distinct orientation of the prime, starting with itself. Symmetric
+    // primes like the block have fewer than eight.
+    pub fn orientations(&self) -> Vec<Prime<'static>> {
+        let mut orientations: Vec<Prime<'static>> = vec![];
+        for &t in TRANSFORMS {
+            let p = self.transform(t);
+            if !orientations.contains(&p) {
+                orientations.push(p);
+            }
+        }
+        orientations
+    }
+}
+
+fn transform_mask(mask: &Mask, transform: Transform, size: usize) -> Mask<'static> {
+    let mut transformed = vec![vec![0; size]; size];
+    for x in 0..size {
+        for y in 0..size {
+            let (tx, ty) = transform.apply(x, y, size);
+            transformed[tx][ty] = mask[x][y];
+        }
+    }
+    Mask::from(transformed)
+}
+
+// The smallest and largest x and y of the alive cells.
+fn bounds(cells: &Cells) -> Option<(usize, usize, usize, usize)> {
+    let mut bounds = None;
+    for x in 0..cells.size_x {
+        for y in 0..cells.size_y {
+            if cells.is_alive(x, y) {
+                let (x0, y0, x1, y1) = bounds.unwrap_or((x, y, x, y));
+                bounds = Some((x0.min(x), y0.min(y), x1.max(x), y1.max(y)));
+            }
+        }
+    }
+    bounds
+}
+
+pub const PRIMES: &[Prime<'static>] = &[
+    BLOCK, TUB, BEEHIVE, BLINKER, BEACON, CLOCK, TOAD,
+    LOAF, BOAT, SHIP, POND, LONG_BOAT, SNAKE, PULSAR, PENTADECATHLON, FIGURE_EIGHT,
+];
+
+pub fn stable_primes(rule: &Rule) -> Result<Vec<Prime<'static>>, String> {
+    stable_primes_with(rule, &[])
+}
+
+// Every orientation of the built-in and `custom` primes that are stable under
+// `rule`. Life-like rules are isotropic, so the orientations of a stable prime
+// are stable too.
+pub fn stable_primes_with(rule: &Rule, custom: &[Prime<'static>]) -> Result<Vec<Prime<'static>>, String> {
+    let primes: Vec<Prime<'static>> = PRIMES.iter()
+        .chain(custom.iter())
+        .filter(|p| p.is_stable(rule))
+        .flat_map(|p| p.orientations())
+        .collect();
+
+    if primes.is_empty() {
+        Err(format!("no still lifes or oscillators are stable under {}", rule))
+    } else {
+        Ok(primes)
+    }
 }
 
 pub const EMPTY: Prime<'static> = Prime {
     prime_type: PrimeType::Empty,
     exclusive_size: 1,
-    occupied: &[&[0]],
-    prime: &[&[0]],
+    period: 1,
+    occupied: Mask::Static(&[&[0]]),
+    phases: Phases::Static(&[&[&[0]]]),
 };
 
 pub const BLOCK: Prime<'static> = Prime {
     prime_type: PrimeType::Block,
     exclusive_size: 4,
-    occupied: &[
-        &[0, 0, 0, 0],
-        &[0, 1, 1, 0],
-        &[0, 1, 1, 0],
-        &[0, 0, 0, 0]
-    ],
-    prime: &[
+    period: 1,
+    occupied: Mask::Static(&[
         &[0, 0, 0, 0],
         &[0, 1, 1, 0],
         &[0, 1, 1, 0],
         &[0, 0, 0, 0]
-    ],
+    ]),
+    phases: Phases::Static(&[
+        &[
+            &[0, 0, 0, 0],
+            &[0, 1, 1, 0],
+            &[0, 1, 1, 0],
+            &[0, 0, 0, 0],
+        ],
+    ]),
 };
 
 pub const TUB: Prime<'static> = Prime {
     prime_type: PrimeType::Tub,
     exclusive_size: 5,
-    occupied: &[
+    period: 1,
+    occupied: Mask::Static(&[
         &[0, 0, 0, 0, 0],
         &[0, 0, 1, 0, 0],
         &[0, 1, 1, 1, 0],
         &[0, 0, 1, 0, 0],
         &[0, 0, 0, 0, 0]
-    ],
-    prime: &[
-        &[0, 0, 0, 0, 0],
-        &[0, 0, 1, 0, 0],
-        &[0, 1, 0, 1, 0],
-        &[0, 0, 1, 0, 0],
-        &[0, 0, 0, 0, 0]
-    ],
+    ]),
+    phases: Phases::Static(&[
+        &[
+            &[0, 0, 0, 0, 0],
+            &[0, 0, 1, 0, 0],
+            &[0, 1, 0, 1, 0],
+            &[0, 0, 1, 0, 0],
+            &[0, 0, 0, 0, 0],
+        ],
+    ]),
 };
 
 pub const BEEHIVE: Prime<'static> = Prime {
     prime_type: PrimeType::Beehive,
     exclusive_size: 6,
-    occupied: &[
+    period: 1,
+    occupied: Mask::Static(&[
         &[0, 0, 0, 0, 0, 0],
         &[0, 0, 1, 1, 0, 0],
         &[0, 1, 1, 1, 1, 0],
         &[0, 0, 1, 1, 0, 0],
         &[0, 0, 0, 0, 0, 0],
         &[0, 0, 0, 0, 0, 0]
-    ],
-    prime: &[
-        &[0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 1, 0, 0],
-        &[0, 1, 0, 0, 1, 0],
-        &[0, 0, 1, 1, 0, 0],
-        &[0, 0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0, 0]
-    ],
+    ]),
+    phases: Phases::Static(&[
+        &[
+            &[0, 0, 0, 0, 0, 0],
+            &[0, 0, 1, 1, 0, 0],
+            &[0, 1, 0, 0, 1, 0],
+            &[0, 0, 1, 1, 0, 0],
+            &[0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0],
+        ],
+    ]),
 };
 
 pub const BLINKER: Prime<'static> = Prime {
     prime_type: PrimeType::Blinker,
     exclusive_size: 5,
-    occupied: &[
+    period: 2,
+    occupied: Mask::Static(&[
         &[0, 0, 0, 0, 0],
         &[0, 0, 1, 0, 0],
         &[0, 1, 1, 1, 0],
         &[0, 0, 1, 0, 0],
         &[0, 0, 0, 0, 0]
-    ],
-    prime: &[
-        &[0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0],
-        &[0, 1, 1, 1, 0],
-        &[0, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 0],
-    ]
+    ]),
+    phases: Phases::Static(&[
+        &[
+            &[0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0],
+            &[0, 1, 1, 1, 0],
+            &[0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0],
+        ],
+        &[
+            &[0, 0, 0, 0, 0],
+            &[0, 0, 1, 0, 0],
+            &[0, 0, 1, 0, 0],
+            &[0, 0, 1, 0, 0],
+            &[0, 0, 0, 0, 0],
+        ],
+    ]),
 };
 
 pub const BEACON: Prime<'static> = Prime {
     prime_type: PrimeType::Beacon,
     exclusive_size: 6,
-    occupied: &[
+    period: 2,
+    occupied: Mask::Static(&[
         &[0, 0, 0, 0, 0, 0],
         &[0, 1, 1, 0, 0, 0],
         &[0, 1, 1, 0, 0, 0],
         &[0, 0, 0, 1, 1, 0],
         &[0, 0, 0, 1, 1, 0],
         &[0, 0, 0, 0, 0, 0]
-    ],
-    prime: &[
-        &[0, 0, 0, 0, 0, 0],
-        &[0, 1, 1, 0, 0, 0],
-        &[0, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 0],
-        &[0, 0, 0, 1, 1, 0],
-        &[0, 0, 0, 0, 0, 0]
-    ],
+    ]),
+    phases: Phases::Static(&[
+        &[
+            &[0, 0, 0, 0, 0, 0],
+            &[0, 1, 1, 0, 0, 0],
+            &[0, 1, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 1, 0],
+            &[0, 0, 0, 1, 1, 0],
+            &[0, 0, 0, 0, 0, 0],
+        ],
+        &[
+            &[0, 0, 0, 0, 0, 0],
+            &[0, 1, 1, 0, 0, 0],
+            &[0, 1, 1, 0, 0, 0],
+            &[0, 0, 0, 1, 1, 0],
+            &[0, 0, 0, 1, 1, 0],
+            &[0, 0, 0, 0, 0, 0],
+        ],
+    ]),
 };
 
 pub const CLOCK: Prime<'static> = Prime {
     prime_type: PrimeType::Clock,
     exclusive_size: 6,
-    occupied: &[
+    period: 2,
+    occupied: Mask::Static(&[
         &[0, 0, 0, 0, 0, 0],
         &[0, 0, 1, 1, 0, 0],
         &[0, 1, 0, 1, 1, 0],
         &[0, 1, 1, 0, 1, 0],
         &[0, 0, 1, 1, 0, 0],
         &[0, 0, 0, 0, 0, 0]
-    ],
-    prime: &[
-        &[0, 0, 0, 0, 0, 0],
-        &[0, 0, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 1, 0],
-        &[0, 1, 1, 0, 0, 0],
-        &[0, 0, 0, 1, 0, 0],
-        &[0, 0, 0, 0, 0, 0]
-    ]
+    ]),
+    phases: Phases::Static(&[
+        &[
+            &[0, 0, 0, 0, 0, 0],
+            &[0, 0, 1, 0, 0, 0],
+            &[0, 0, 0, 1, 1, 0],
+            &[0, 1, 1, 0, 0, 0],
+            &[0, 0, 0, 1, 0, 0],
+            &[0, 0, 0, 0, 0, 0],
+        ],
+        &[
+            &[0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 1, 0, 0],
+            &[0, 1, 0, 1, 0, 0],
+            &[0, 0, 1, 0, 1, 0],
+            &[0, 0, 1, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0],
+        ],
+    ]),
 };
 
 pub const TOAD: Prime<'static> = Prime {
     prime_type: PrimeType::Toad,
     exclusive_size: 6,
-    occupied: &[
+    period: 2,
+    occupied: Mask::Static(&[
         &[0, 0, 0, 0, 0, 0],
         &[0, 0, 1, 1, 0, 0],
         &[0, 1, 1, 1, 0, 0],
         &[0, 0, 1, 1, 1, 0],
         &[0, 0, 1, 1, 0, 0],
         &[0, 0, 0, 0, 0, 0]
-    ],
-    prime: &[
+    ]),
+    phases: Phases::Static(&[
+        &[
+            &[0, 0, 0, 0, 0, 0],
+            &[0, 0, 1, 1, 0, 0],
+            &[0, 1, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 1, 0],
+            &[0, 0, 1, 1, 0, 0],
+            &[0, 0, 0, 0, 0, 0],
+        ],
+        &[
+            &[0, 0, 0, 0, 0, 0],
+            &[0, 0, 1, 0, 0, 0],
+            &[0, 0, 1, 1, 0, 0],
+            &[0, 0, 1, 1, 0, 0],
+            &[0, 0, 0, 1, 0, 0],
+            &[0, 0, 0, 0, 0, 0],
+        ],
+    ]),
+};
+
+pub const LOAF: Prime<'static> = Prime {
+    prime_type: PrimeType::Loaf,
+    exclusive_size: 6,
+    period: 1,
+    occupied: Mask::Static(&[
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 0, 0, 0],
+        &[0, 1, 0, 1, 0, 0],
+        &[0, 1, 0, 0, 1, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+    ]),
+    phases: Phases::Static(&[
+        &[
+            &[0, 0, 0, 0, 0, 0],
+            &[0, 0, 1, 0, 0, 0],
+            &[0, 1, 0, 1, 0, 0],
+            &[0, 1, 0, 0, 1, 0],
+            &[0, 0, 1, 1, 0, 0],
+            &[0, 0, 0, 0, 0, 0],
+        ],
+    ]),
+};
+
+pub const BOAT: Prime<'static> = Prime {
+    prime_type: PrimeType::Boat,
+    exclusive_size: 5,
+    period: 1,
+    occupied: Mask::Static(&[
+        &[0, 0, 0, 0, 0],
+        &[0, 1, 1, 0, 0],
+        &[0, 1, 0, 1, 0],
+        &[0, 0, 1, 0, 0],
+        &[0, 0, 0, 0, 0],
+    ]),
+    phases: Phases::Static(&[
+        &[
+            &[0, 0, 0, 0, 0],
+            &[0, 1, 1, 0, 0],
+            &[0, 1, 0, 1, 0],
+            &[0, 0, 1, 0, 0],
+            &[0, 0, 0, 0, 0],
+        ],
+    ]),
+};
+
+pub const SHIP: Prime<'static> = Prime {
+    prime_type: PrimeType::Ship,
+    exclusive_size: 5,
+    period: 1,
+    occupied: Mask::Static(&[
+        &[0, 0, 0, 0, 0],
+        &[0, 1, 1, 0, 0],
+        &[0, 1, 0, 1, 0],
+        &[0, 0, 1, 1, 0],
+        &[0, 0, 0, 0, 0],
+    ]),
+    phases: Phases::Static(&[
+        &[
+            &[0, 0, 0, 0, 0],
+            &[0, 1, 1, 0, 0],
+            &[0, 1, 0, 1, 0],
+            &[0, 0, 1, 1, 0],
+            &[0, 0, 0, 0, 0],
+        ],
+    ]),
+};
+
+pub const POND: Prime<'static> = Prime {
+    prime_type: PrimeType::Pond,
+    exclusive_size: 6,
+    period: 1,
+    occupied: Mask::Static(&[
         &[0, 0, 0, 0, 0, 0],
         &[0, 0, 1, 1, 0, 0],
-        &[0, 1, 0, 0, 0, 0],
-        &[0, 0, 0, 0, 1, 0],
+        &[0, 1, 0, 0, 1, 0],
+        &[0, 1, 0, 0, 1, 0],
         &[0, 0, 1, 1, 0, 0],
-        &[0, 0, 0, 0, 0, 0]
-    ]
+        &[0, 0, 0, 0, 0, 0],
+    ]),
+    phases: Phases::Static(&[
+        &[
+            &[0, 0, 0, 0, 0, 0],
+            &[0, 0, 1, 1, 0, 0],
+            &[0, 1, 0, 0, 1, 0],
+            &[0, 1, 0, 0, 1, 0],
+            &[0, 0, 1, 1, 0, 0],
+            &[0, 0, 0, 0, 0, 0],
+        ],
+    ]),
 };
 
+pub const LONG_BOAT: Prime<'static> = Prime {
+    prime_type: PrimeType::LongBoat,
+    exclusive_size: 6,
+    period: 1,
+    occupied: Mask::Static(&[
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 1, 1, 0, 0, 0],
+        &[0, 1, 0, 1, 0, 0],
+        &[0, 0, 1, 0, 1, 0],
+        &[0, 0, 0, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+    ]),
+    phases: Phases::Static(&[
+        &[
+            &[0, 0, 0, 0, 0, 0],
+            &[0, 1, 1, 0, 0, 0],
+            &[0, 1, 0, 1, 0, 0],
+            &[0, 0, 1, 0, 1, 0],
+            &[0, 0, 0, 1, 0, 0],
+            &[0, 0, 0, 0, 0, 0],
+        ],
+    ]),
+};
+
+pub const SNAKE: Prime<'static> = Prime {
+    prime_type: PrimeType::Snake,
+    exclusive_size: 6,
+    period: 1,
+    occupied: Mask::Static(&[
+        &[0, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 1, 0, 0, 0],
+        &[0, 0, 0, 1, 0, 0],
+        &[0, 0, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 0],
+    ]),
+    phases: Phases::Static(&[
+        &[
+            &[0, 0, 0, 0, 0, 0],
+            &[0, 0, 1, 1, 0, 0],
+            &[0, 0, 1, 0, 0, 0],
+            &[0, 0, 0, 1, 0, 0],
+            &[0, 0, 1, 1, 0, 0],
+            &[0, 0, 0, 0, 0, 0],
+        ],
+    ]),
+};
+
+pub const PULSAR: Prime<'static> = Prime {
+    prime_type: PrimeType::Pulsar,
+    exclusive_size: 17,
+    period: 3,
+    occupied: Mask::Static(&[
+        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 0, 0, 1, 0, 1, 0, 1, 0, 1, 0, 0, 1, 0, 0],
+        &[0, 1, 1, 1, 1, 0, 1, 1, 0, 1, 1, 0, 1, 1, 1, 1, 0],
+        &[0, 0, 1, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0],
+        &[0, 0, 1, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 1, 0, 0],
+        &[0, 1, 1, 1, 1, 0, 1, 1, 0, 1, 1, 0, 1, 1, 1, 1, 0],
+        &[0, 0, 1, 0, 0, 1, 0, 1, 0, 1, 0, 1, 0, 0, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    ]),
+    phases: Phases::Static(&[
+        &[
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 1, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 0],
+            &[0, 0, 1, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 0],
+            &[0, 0, 1, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 0],
+            &[0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0],
+            &[0, 0, 1, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 0],
+            &[0, 0, 1, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 0],
+            &[0, 0, 1, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ],
+        &[
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 1, 1, 1, 0, 0, 1, 1, 0, 1, 1, 0, 0, 1, 1, 1, 0],
+            &[0, 0, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 0, 0],
+            &[0, 1, 1, 1, 0, 0, 1, 1, 0, 1, 1, 0, 0, 1, 1, 1, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ],
+        &[
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0],
+            &[0, 0, 1, 0, 0, 1, 0, 1, 0, 1, 0, 1, 0, 0, 1, 0, 0],
+            &[0, 0, 1, 1, 1, 0, 1, 1, 0, 1, 1, 0, 1, 1, 1, 0, 0],
+            &[0, 0, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 0, 0],
+            &[0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0],
+            &[0, 0, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 0, 0],
+            &[0, 0, 1, 1, 1, 0, 1, 1, 0, 1, 1, 0, 1, 1, 1, 0, 0],
+            &[0, 0, 1, 0, 0, 1, 0, 1, 0, 1, 0, 1, 0, 0, 1, 0, 0],
+            &[0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ],
+    ]),
+};
+
+pub const PENTADECATHLON: Prime<'static> = Prime {
+    prime_type: PrimeType::Pentadecathlon,
+    exclusive_size: 18,
+    period: 15,
+    occupied: Mask::Static(&[
+        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    ]),
+    phases: Phases::Static(&[
+        &[
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ],
+        &[
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ],
+        &[
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ],
+        &[
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 1, 1, 1, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 1, 1, 1, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 1, 1, 1, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 1, 1, 1, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ],
+        &[
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ],
+        &[
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ],
+        &[
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ],
+        &[
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ],
+        &[
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ],
+        &[
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ],
+        &[
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ],
+        &[
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ],
+        &[
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ],
+        &[
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ],
+        &[
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ],
+    ]),
+};
+
+pub const FIGURE_EIGHT: Prime<'static> = Prime {
+    prime_type: PrimeType::FigureEight,
+    exclusive_size: 12,
+    period: 8,
+    occupied: Mask::Static(&[
+        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0],
+        &[0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0],
+        &[0, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0],
+        &[0, 0, 1, 1, 1, 1, 0, 1, 0, 0, 0, 0],
+        &[0, 0, 0, 0, 1, 0, 1, 1, 1, 1, 0, 0],
+        &[0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 0],
+        &[0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0],
+        &[0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0],
+        &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    ]),
+    phases: Phases::Static(&[
+        &[
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ],
+        &[
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ],
+        &[
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 1, 1, 1, 0, 1, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 1, 0, 1, 1, 1, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ],
+        &[
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0],
+            &[0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 1, 0, 1, 0, 0, 1, 0, 0],
+            &[0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ],
+        &[
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0],
+            &[0, 1, 1, 1, 0, 0, 1, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 1, 0, 0, 1, 1, 1, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ],
+        &[
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
+            &[0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0],
+            &[0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ],
+        &[
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 1, 0, 1, 1, 1, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 1, 1, 1, 0, 1, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ],
+        &[
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ],
+    ]),
+};
+
+#[test]
+fn test_is_stable() {
+    for prime in PRIMES {
+        assert!(prime.is_stable(&Rule::conway()), "{:?}", prime.prime_type);
+    }
+
+    let seeds = "B2/S".parse().unwrap();
+    assert!(!BLOCK.is_stable(&seeds));
+    assert!(!BLINKER.is_stable(&seeds));
+}
+
+#[test]
+fn test_phases() {
+    for prime in PRIMES {
+        assert_eq!(prime.period, prime.phases.len(), "{:?}", prime.prime_type);
+        for phase in prime.phases.iter() {
+            for x in 0..prime.exclusive_size {
+                for y in 0..prime.exclusive_size {
+                    if phase[x][y] > 0 {
+                        assert!(prime.occupied[x][y] > 0, "{:?}", prime.prime_type);
+                    }
+                }
+            }
+        }
+    }
+
+    assert_eq!(BLINKER.phases.get(0), BLINKER.phase(2));
+    assert_eq!(BLINKER.phases.get(1), BLINKER.phase(3));
+}
+
+#[test]
+fn test_stable_primes() {
+    let conway = stable_primes(&Rule::conway()).unwrap();
+    for prime in PRIMES {
+        assert!(conway.contains(prime));
+    }
+    assert_eq!(1, conway.iter().filter(|p| p.prime_type == PrimeType::Block).count());
+    assert_eq!(4, conway.iter().filter(|p| p.prime_type == PrimeType::Snake).count());
+    assert!(stable_primes(&"B2/S".parse().unwrap()).is_err());
+
+    let life_without_death = stable_primes(&"B3/S012345678".parse().unwrap()).unwrap();
+    assert!(life_without_death.contains(&BLOCK));
+    assert!(!life_without_death.contains(&BLINKER));
+}
+
+#[test]
+fn test_transform() {
+    let vertical = BLINKER.transform(Transform::Rotate90);
+    assert_eq!(BLINKER.phases.get(1), vertical.phases.get(0));
+    assert_eq!(BLINKER.phases.get(0), vertical.phases.get(1));
+    assert_eq!(vertical, BLINKER.transform(Transform::Rotate90));
+    assert_eq!(BLINKER, vertical.transform(Transform::Rotate270));
+    assert_eq!(vertical, BLINKER.transform(Transform::Rotate270));
+
+    for prime in PRIMES {
+        for &t in TRANSFORMS {
+            let p = prime.transform(t);
+            assert!(p.is_stable(&Rule::conway()), "{:?} {:?}", prime.prime_type, t);
+            assert_eq!(prime.period, p.phases.len());
+        }
+    }
+}
+
+#[test]
+fn test_orientations() {
+    assert_eq!(vec![BLOCK], BLOCK.orientations());
+    assert_eq!(2, BLINKER.orientations().len());
+    assert_eq!(4, BOAT.orientations().len());
+    assert_eq!(BOAT, BOAT.orientations()[0]);
+}
+
+#[test]
+fn test_from_pattern() {
+    let conway = Rule::conway();
+    assert_eq!(Ok(BLOCK.phases), Prime::from_pattern("2o$2o!", &conway).map(|p| p.phases));
+    assert_eq!(Ok(BLINKER.phases), Prime::from_pattern("o$o$o!", &conway).map(|p| p.phases));
+    assert_eq!(Ok(BOAT.occupied), Prime::from_pattern("OO.\nO.O\n.O.", &conway).map(|p| p.occupied));
+
+    let pulsar = Prime::from_pattern(
+        "2b3o3b3o2$o4bobo4bo$o4bobo4bo$o4bobo4bo$2b3o3b3o2$2b3o3b3o$o4bobo4bo$o4bobo4bo$o4bobo4bo2$2b3o3b3o!",
+        &conway).unwrap();
+    assert_eq!(PrimeType::Custom, pulsar.prime_type);
+    assert_eq!(PULSAR.period, pulsar.period);
+    assert_eq!(PULSAR.exclusive_size, pulsar.exclusive_size);
+    assert!(pulsar.is_stable(&conway));
+
+    // A glider moves away, and an R-pentomino grows for over a thousand generations.
+    assert!(Prime::from_pattern("bo$2bo$3o!", &conway).is_err());
+    assert!(Prime::from_pattern("b2o$2o$bo!", &conway).is_err());
+    assert!(Prime::from_pattern("3o!", &"B2/S".parse().unwrap()).is_err());
+    assert!(Prime::from_pattern("99999999999999999999999o!", &conway).is_err());
+    assert!(Prime::from_pattern("4000$4000bo!", &conway).is_err());
+    assert!(Prime::from_pattern("257o!", &conway).is_err());
+
+    // Runtime primes compare by their cells, like the built-in ones.
+    let first = Prime::from_pattern("b2o$o2bo$b2o!", &conway).unwrap();
+    let second = Prime::from_pattern("#N Beehive\nb2o$o2bo$b2o!", &conway).unwrap();
+    assert_eq!(first, second);
+}
+
+#[test]
+fn test_stable_primes_with() {
+    let conway = Rule::conway();
+    let custom = Prime::from_pattern("2o$obo$bo!", &conway).unwrap();
+    assert!(stable_primes_with(&conway, ::std::slice::from_ref(&custom)).unwrap().contains(&custom));
+    assert!(stable_primes_with(&"B2/S".parse().unwrap(), &[custom]).is_err());
+}
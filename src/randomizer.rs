@@ -20,3 +20,58 @@ impl Randomizer for Rand {
         self.source.gen()
     }
 }
+
+// SplitMix64, so that the same seed yields the same numbers on every platform.
+pub struct SplitMix {
+    state: u64,
+}
+
+impl SplitMix {
+    pub fn from_seed(seed: u64) -> SplitMix {
+        SplitMix {
+            state: seed,
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+impl Randomizer for SplitMix {
+    fn new() -> Self {
+        SplitMix::from_seed(0)
+    }
+
+    fn random_number(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+#[test]
+fn test_split_mix() {
+    let mut rand = SplitMix::from_seed(1234567);
+
+    assert_eq!(6457827717110365317, rand.next_u64());
+    assert_eq!(3203168211198807973, rand.next_u64());
+    assert_eq!(9817491932198370423, rand.next_u64());
+}
+
+#[test]
+fn test_split_mix_is_reproducible() {
+    let mut rand1 = SplitMix::from_seed(42);
+    let mut rand2 = SplitMix::from_seed(42);
+    let mut rand3 = SplitMix::from_seed(43);
+
+    let numbers1: Vec<f64> = (0..100).map(|_| rand1.random_number()).collect();
+    let numbers2: Vec<f64> = (0..100).map(|_| rand2.random_number()).collect();
+    let numbers3: Vec<f64> = (0..100).map(|_| rand3.random_number()).collect();
+
+    assert_eq!(numbers1, numbers2);
+    assert_ne!(numbers1, numbers3);
+    assert!(numbers1.iter().all(|n| (0.0..1.0).contains(n)));
+}
@@ -0,0 +1,239 @@
+use std::str::FromStr;
+use cells::*;
+
+// Samples per cell along each axis when measuring coverage.
+const SAMPLES: usize = 4;
+// Line segments each curve is flattened into.
+const CURVE_STEPS: usize = 8;
+
+// Which points enclosed by a path are inside it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FillRule {
+    NonZero,
+    EvenOdd,
+}
+
+impl FromStr for FillRule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<FillRule, String> {
+        match s.to_lowercase().as_str() {
+            "nonzero" => Ok(FillRule::NonZero),
+            "evenodd" => Ok(FillRule::EvenOdd),
+            _ => Err(format!("unknown fill rule: {}", s)),
+        }
+    }
+}
+
+// An outline made of closed polygons, in cell coordinates with y pointing
+// down. Curves are flattened into line segments as they are added.
+pub struct Path {
+    pub edges: Vec<(f64, f64, f64, f64)>,
+    start: (f64, f64),
+    current: (f64, f64),
+}
+
+impl Path {
+    pub fn new() -> Path {
+        Path {
+            edges: Vec::new(),
+            start: (0.0, 0.0),
+            current: (0.0, 0.0),
+        }
+    }
+
+    pub fn move_to(&mut self, x: f64, y: f64) {
+        self.close();
+        self.start = (x, y);
+        self.current = (x, y);
+    }
+
+    pub fn line_to(&mut self, x: f64, y: f64) {
+        let (x0, y0) = self.current;
+        if y0 != y {
+            self.edges.push((x0, y0, x, y));
+        }
+        self.current = (x, y);
+    }
+
+    pub fn quad_to(&mut self, x1: f64, y1: f64, x: f64, y: f64) {
+        let (x0, y0) = self.current;
+        for i in 1..CURVE_STEPS + 1 {
+            let t = i as f64 / CURVE_STEPS as f64;
+            let u = 1.0 - t;
+            self.line_to(
+                u * u * x0 + 2.0 * u * t * x1 + t * t * x,
+                u * u * y0 + 2.0 * u * t * y1 + t * t * y,
+            );
+        }
+    }
+
+    pub fn cubic_to(&mut self, x1: f64, y1: f64, x2: f64, y2: f64, x: f64, y: f64) {
+        let (x0, y0) = self.current;
+        for i in 1..CURVE_STEPS + 1 {
+            let t = i as f64 / CURVE_STEPS as f64;
+            let u = 1.0 - t;
+            self.line_to(
+                u * u * u * x0 + 3.0 * u * u * t * x1 + 3.0 * u * t * t * x2 + t * t * t * x,
+                u * u * u * y0 + 3.0 * u * u * t * y1 + 3.0 * u * t * t * y2 + t * t * t * y,
+            );
+        }
+    }
+
+    // A copy of the path scaled by `scale` and then moved by (dx, dy).
+    pub fn transformed(&self, scale: f64, dx: f64, dy: f64) -> Path {
+        let map = |x: f64, y: f64| (x * scale + dx, y * scale + dy);
+        let (start, current) = (map(self.start.0, self.start.1), map(self.current.0, self.current.1));
+        Path {
+            edges: self.edges.iter()
+                .map(|&(x0, y0, x1, y1)| (x0 * scale + dx, y0 * scale + dy, x1 * scale + dx, y1 * scale + dy))
+                .collect(),
+            start: start,
+            current: current,
+        }
+    }
+
+    // Closes the current polygon back to where it started.
+    pub fn close(&mut self) {
+        let (x, y) = self.start;
+        self.line_to(x, y);
+    }
+}
+
+// The fraction of each cell covered by the path, row by row, measured on a
+// grid of SAMPLES x SAMPLES points per cell.
+pub fn coverage(path: &Path, size_x: usize, size_y: usize, fill_rule: FillRule) -> Vec<f64> {
+    let mut coverage = vec![0.0; size_x * size_y];
+    let step = 1.0 / SAMPLES as f64;
+    let weight = step * step;
+
+    for row in 0..size_y * SAMPLES {
+        let y = (row as f64 + 0.5) * step;
+        // Where the edges cross the scanline, and in which direction.
+        let mut crossings: Vec<(f64, i32)> = path.edges.iter()
+            .filter(|&&(_, y0, _, y1)| (y0 <= y && y < y1) || (y1 <= y && y < y0))
+            .map(|&(x0, y0, x1, y1)| {
+                let x = x0 + (y - y0) / (y1 - y0) * (x1 - x0);
+                (x, if y1 > y0 { 1 } else { -1 })
+            })
+            .filter(|&(x, _)| !x.is_nan())
+            .collect();
+        crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut winding = 0;
+        for pair in crossings.windows(2) {
+            winding += pair[0].1;
+            let inside = match fill_rule {
+                FillRule::NonZero => winding != 0,
+                FillRule::EvenOdd => winding % 2 != 0,
+            };
+            if !inside {
+                continue;
+            }
+            // Count the samples between this crossing and the next.
+            let first = ((pair[0].0 / step - 0.5).ceil().max(0.0)) as usize;
+            let last = ((pair[1].0 / step - 0.5).ceil().max(0.0)) as usize;
+            for sample in first..last.min(size_x * SAMPLES) {
+                coverage[row / SAMPLES * size_x + sample / SAMPLES] += weight;
+            }
+        }
+    }
+    coverage
+}
+
+// Fills the paths one on top of another, each with its own fill rule. Cells
+// covered by at least `threshold` of their area are alive.
+pub fn rasterize(paths: &[(&Path, FillRule)], size_x: usize, size_y: usize, threshold: f64) -> Cells {
+    let mut total = vec![0.0; size_x * size_y];
+    for &(path, fill_rule) in paths {
+        for (t, c) in total.iter_mut().zip(coverage(path, size_x, size_y, fill_rule)) {
+            *t += c;
+        }
+    }
+    let mut cells = Cells::new(size_x, size_y);
+    for y in 0..size_y {
+        for x in 0..size_x {
+            if total[y * size_x + x] >= threshold {
+                cells.make_alive(x, y);
+            }
+        }
+    }
+    cells
+}
+
+#[cfg(test)]
+fn rectangle(path: &mut Path, x0: f64, y0: f64, x1: f64, y1: f64) {
+    path.move_to(x0, y0);
+    path.line_to(x1, y0);
+    path.line_to(x1, y1);
+    path.line_to(x0, y1);
+    path.close();
+}
+
+#[test]
+fn test_coverage() {
+    let mut path = Path::new();
+    rectangle(&mut path, 1.0, 1.0, 2.5, 3.0);
+    let coverage = coverage(&path, 4, 4, FillRule::NonZero);
+
+    assert_eq!(0.0, coverage[0]);
+    assert_eq!(1.0, coverage[4 + 1]);
+    assert_eq!(0.5, coverage[4 + 2]);
+    assert_eq!(1.0, coverage[2 * 4 + 1]);
+    assert_eq!(0.0, coverage[3 * 4 + 1]);
+}
+
+#[test]
+fn test_fill_rule() {
+    // Two nested squares drawn in the same direction.
+    let mut path = Path::new();
+    rectangle(&mut path, 0.0, 0.0, 6.0, 6.0);
+    rectangle(&mut path, 2.0, 2.0, 4.0, 4.0);
+
+    let nonzero = rasterize(&[(&path, FillRule::NonZero)], 6, 6, 0.5);
+    let evenodd = rasterize(&[(&path, FillRule::EvenOdd)], 6, 6, 0.5);
+    assert_eq!(36, nonzero.count_alive());
+    assert_eq!(32, evenodd.count_alive());
+    assert!(!evenodd.is_alive(3, 3));
+}
+
+#[test]
+fn test_curves() {
+    // A circle of radius 4 made of four cubic arcs.
+    let k = 4.0 * 0.5523;
+    let mut path = Path::new();
+    path.move_to(9.0, 5.0);
+    path.cubic_to(9.0, 5.0 + k, 5.0 + k, 9.0, 5.0, 9.0);
+    path.cubic_to(5.0 - k, 9.0, 1.0, 5.0 + k, 1.0, 5.0);
+    path.cubic_to(1.0, 5.0 - k, 5.0 - k, 1.0, 5.0, 1.0);
+    path.cubic_to(5.0 + k, 1.0, 9.0, 5.0 - k, 9.0, 5.0);
+    path.close();
+    let cells = rasterize(&[(&path, FillRule::NonZero)], 10, 10, 0.5);
+
+    let area = cells.count_alive() as f64;
+    assert!((area - 16.0 * ::std::f64::consts::PI).abs() < 4.0, "{}", area);
+    assert!(cells.is_alive(5, 5) && !cells.is_alive(1, 1));
+
+    let mut path = Path::new();
+    path.move_to(0.0, 0.0);
+    path.quad_to(4.0, 8.0, 8.0, 0.0);
+    path.close();
+    let cells = rasterize(&[(&path, FillRule::NonZero)], 8, 8, 0.5);
+    assert!(cells.is_alive(4, 2) && !cells.is_alive(4, 5));
+}
+
+#[test]
+fn test_coverage_ignores_nan() {
+    let mut path = Path::new();
+    rectangle(&mut path, f64::INFINITY, 0.0, 2.0, 2.0);
+    path.line_to(f64::NAN, 3.0);
+    let coverage = coverage(&path, 4, 4, FillRule::NonZero);
+    assert_eq!(16, coverage.len());
+}
+
+#[test]
+fn test_fill_rule_from_str() {
+    assert_eq!(Ok(FillRule::EvenOdd), "evenodd".parse());
+    assert_eq!(Ok(FillRule::NonZero), "nonzero".parse());
+    assert!("odd".parse::<FillRule>().is_err());
+}
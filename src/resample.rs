@@ -0,0 +1,172 @@
+use std::str::FromStr;
+use cells::*;
+
+// How a bitmap is scaled to another size.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Resampling {
+    // Each cell copies the source cell under its centre.
+    Nearest,
+    // Interpolates between the four nearest source cells and keeps the cells
+    // that end up at least half alive, rounding off corners.
+    Bilinear,
+    // Doubles the bitmap with Scale2x until it is large enough, so diagonal
+    // strokes stay diagonal, then samples the nearest cell.
+    Scale2x,
+}
+
+impl FromStr for Resampling {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Resampling, String> {
+        match s.to_lowercase().as_str() {
+            "nearest" => Ok(Resampling::Nearest),
+            "bilinear" => Ok(Resampling::Bilinear),
+            "scale2x" => Ok(Resampling::Scale2x),
+            _ => Err(format!("unknown resampling: {}", s)),
+        }
+    }
+}
+
+pub fn resample(cells: &Cells, size_x: usize, size_y: usize, resampling: Resampling) -> Cells {
+    match resampling {
+        Resampling::Nearest => nearest(cells, size_x, size_y),
+        Resampling::Bilinear => bilinear(cells, size_x, size_y),
+        Resampling::Scale2x => {
+            let mut cells = cells.clone();
+            while cells.size_x > 0 && cells.size_y > 0 && (cells.size_x < size_x || cells.size_y < size_y) {
+                cells = scale2x(&cells);
+            }
+            nearest(&cells, size_x, size_y)
+        },
+    }
+}
+
+// Whether the source cell is alive, treating the cells beyond the edges as
+// copies of the nearest edge cell.
+fn is_alive_clamped(cells: &Cells, x: isize, y: isize) -> bool {
+    let x = x.max(0).min(cells.size_x as isize - 1) as usize;
+    let y = y.max(0).min(cells.size_y as isize - 1) as usize;
+    cells.is_alive(x, y)
+}
+
+fn nearest(cells: &Cells, size_x: usize, size_y: usize) -> Cells {
+    let mut target = Cells::new(size_x, size_y);
+    if cells.size_x == 0 || cells.size_y == 0 {
+        return target;
+    }
+    for x in 0..size_x {
+        for y in 0..size_y {
+            let fx = (2 * x + 1) * cells.size_x / (2 * size_x);
+            let fy = (2 * y + 1) * cells.size_y / (2 * size_y);
+            if cells.is_alive(fx, fy) {
+                target.make_alive(x, y);
+            }
+        }
+    }
+    target
+}
+
+fn bilinear(cells: &Cells, size_x: usize, size_y: usize) -> Cells {
+    let mut target = Cells::new(size_x, size_y);
+    if cells.size_x == 0 || cells.size_y == 0 {
+        return target;
+    }
+    let value = |x: isize, y: isize| if is_alive_clamped(cells, x, y) { 1.0 } else { 0.0 };
+    for x in 0..size_x {
+        for y in 0..size_y {
+            // The centre of the target cell in source coordinates, relative to
+            // the centres of the source cells.
+            let u = (x as f64 + 0.5) * cells.size_x as f64 / size_x as f64 - 0.5;
+            let v = (y as f64 + 0.5) * cells.size_y as f64 / size_y as f64 - 0.5;
+            let (x0, y0) = (u.floor() as isize, v.floor() as isize);
+            let (fx, fy) = (u - u.floor(), v - v.floor());
+            let top = value(x0, y0) * (1.0 - fx) + value(x0 + 1, y0) * fx;
+            let bottom = value(x0, y0 + 1) * (1.0 - fx) + value(x0 + 1, y0 + 1) * fx;
+            if top * (1.0 - fy) + bottom * fy >= 0.5 {
+                target.make_alive(x, y);
+            }
+        }
+    }
+    target
+}
+
+// Doubles the bitmap, filling in the corner between two cells that touch
+// diagonally so the outline of a stroke stays smooth.
+fn scale2x(cells: &Cells) -> Cells {
+    let mut target = Cells::new(cells.size_x * 2, cells.size_y * 2);
+    for x in 0..cells.size_x {
+        for y in 0..cells.size_y {
+            let (ix, iy) = (x as isize, y as isize);
+            let p = cells.is_alive(x, y);
+            let a = is_alive_clamped(cells, ix, iy - 1);
+            let b = is_alive_clamped(cells, ix + 1, iy);
+            let c = is_alive_clamped(cells, ix - 1, iy);
+            let d = is_alive_clamped(cells, ix, iy + 1);
+            let corners = [
+                (0, 0, if c == a && c != d && a != b { a } else { p }),
+                (1, 0, if a == b && a != c && b != d { b } else { p }),
+                (0, 1, if d == c && d != b && c != a { c } else { p }),
+                (1, 1, if b == d && b != a && d != c { d } else { p }),
+            ];
+            for &(dx, dy, alive) in corners.iter() {
+                if alive {
+                    target.make_alive(2 * x + dx, 2 * y + dy);
+                }
+            }
+        }
+    }
+    target
+}
+
+#[test]
+fn test_nearest() {
+    let cells = Cells::from_vec(vec![vec![1, 0], vec![0, 1]]);
+    let scaled = resample(&cells, 5, 5, Resampling::Nearest);
+
+    assert_eq!(Cells::from_vec(vec![
+        vec![1, 1, 0, 0, 0],
+        vec![1, 1, 0, 0, 0],
+        vec![0, 0, 1, 1, 1],
+        vec![0, 0, 1, 1, 1],
+        vec![0, 0, 1, 1, 1],
+    ]), scaled);
+    assert_eq!(cells, resample(&scaled, 2, 2, Resampling::Nearest));
+}
+
+#[test]
+fn test_bilinear() {
+    let mut cells = Cells::new(3, 3);
+    cells.make_alive(1, 1);
+    let scaled = resample(&cells, 9, 9, Resampling::Bilinear);
+
+    // The corners of the square are rounded off.
+    assert_eq!(5, scaled.count_alive());
+    assert!(scaled.is_alive(4, 3) && scaled.is_alive(4, 4) && !scaled.is_alive(3, 3));
+
+    let full = Cells::from_vec(vec![vec![1, 1], vec![1, 1]]);
+    assert_eq!(25, resample(&full, 5, 5, Resampling::Bilinear).count_alive());
+}
+
+#[test]
+fn test_scale2x() {
+    let cells = Cells::from_vec(vec![vec![1, 0], vec![0, 1]]);
+
+    assert_eq!(Cells::from_vec(vec![
+        vec![1, 1, 0, 0],
+        vec![1, 0, 1, 0],
+        vec![0, 1, 0, 1],
+        vec![0, 0, 1, 1],
+    ]), resample(&cells, 4, 4, Resampling::Scale2x));
+
+    // Straight edges are kept as they are.
+    let bar = Cells::from_vec(vec![vec![1, 1, 1], vec![0, 0, 0], vec![0, 0, 0]]);
+    assert_eq!(resample(&bar, 6, 6, Resampling::Nearest), resample(&bar, 6, 6, Resampling::Scale2x));
+}
+
+#[test]
+fn test_resampling_from_str() {
+    assert_eq!(Ok(Resampling::Nearest), "nearest".parse());
+    assert_eq!(Ok(Resampling::Bilinear), "Bilinear".parse());
+    assert_eq!(Ok(Resampling::Scale2x), "scale2x".parse());
+    assert!("bicubic".parse::<Resampling>().is_err());
+}
@@ -0,0 +1,95 @@
+// Longer runs are rejected rather than expanded.
+const MAX_RUN: usize = 4096;
+
+// Parses the alive cells of a pattern written either in RLE, e.g.
+// "x = 3, y = 3\nbo$2bo$3o!", or in plaintext, e.g. "!Name: Glider\n.O\n..O\nOOO".
+// Coordinates are (x, y) from the top left corner.
+pub fn parse(s: &str) -> Result<Vec<(usize, usize)>, String> {
+    let is_plaintext = s.lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.starts_with('!'))
+        .all(|line| line.chars().all(|c| c == '.' || c == 'O' || c == '*'));
+
+    let cells = if is_plaintext { parse_plaintext(s) } else { parse_rle(s)? };
+    if cells.is_empty() {
+        Err("the pattern has no alive cells".to_string())
+    } else {
+        Ok(cells)
+    }
+}
+
+fn parse_plaintext(s: &str) -> Vec<(usize, usize)> {
+    let mut cells = Vec::new();
+    let lines = s.lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.starts_with('!'));
+    for (y, line) in lines.enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            if c != '.' {
+                cells.push((x, y));
+            }
+        }
+    }
+    cells
+}
+
+// Comment lines start with '#' and the header starts with 'x'. The rule in
+// the header is ignored: primes are checked against the rule of the board.
+fn parse_rle(s: &str) -> Result<Vec<(usize, usize)>, String> {
+    let mut cells = Vec::new();
+    let (mut x, mut y) = (0, 0);
+    let mut count: usize = 0;
+    let lines = s.lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.starts_with('#') && !line.starts_with('x'));
+    'lines: for line in lines {
+        for c in line.chars() {
+            if let Some(d) = c.to_digit(10) {
+                count = match count.checked_mul(10).and_then(|n| n.checked_add(d as usize)) {
+                    Some(n) if n <= MAX_RUN => n,
+                    _ => return Err(format!("run longer than {} cells in RLE", MAX_RUN)),
+                };
+                continue;
+            }
+            let run = count.max(1);
+            count = 0;
+            match c {
+                'b' | '.' => x += run,
+                '$' => {
+                    x = 0;
+                    y += run;
+                },
+                '!' => break 'lines,
+                c if c.is_ascii_alphabetic() => {
+                    for _ in 0..run {
+                        cells.push((x, y));
+                        x += 1;
+                    }
+                },
+                c if c.is_whitespace() => {},
+                _ => return Err(format!("invalid character in RLE: {}", c)),
+            }
+        }
+    }
+    Ok(cells)
+}
+
+#[test]
+fn test_parse_rle() {
+    let glider = vec![(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)];
+    assert_eq!(Ok(glider.clone()), parse("x = 3, y = 3, rule = B3/S23\nbo$2bo$3o!"));
+    assert_eq!(Ok(glider), parse("#N Glider\nbo$2b\no$3o!"));
+    assert_eq!(Ok(vec![(0, 0), (0, 2)]), parse("o2$o!"));
+    assert_eq!(Ok(vec![(11, 0)]), parse("11bo!"));
+    assert!(parse("bo$2bo$3q?!").is_err());
+    assert!(parse("99999999999999999999999o!").is_err());
+    assert!(parse("4097o!").is_err());
+}
+
+#[test]
+fn test_parse_plaintext() {
+    let glider = vec![(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)];
+    assert_eq!(Ok(glider.clone()), parse("!Name: Glider\n.O\n..O\nOOO"));
+    assert_eq!(Ok(glider), parse(".*.\n..*\n***\n"));
+    assert!(parse("!Empty\n...\n").is_err());
+}
@@ -0,0 +1,121 @@
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rule {
+    pub birth: [bool; 9],
+    pub survival: [bool; 9],
+}
+
+impl Rule {
+    pub fn conway() -> Rule {
+        let mut rule = Rule {
+            birth: [false; 9],
+            survival: [false; 9],
+        };
+        rule.birth[3] = true;
+        rule.survival[2] = true;
+        rule.survival[3] = true;
+        rule
+    }
+
+    pub fn next_state(&self, alive: bool, count: usize) -> bool {
+        if alive {
+            self.survival[count]
+        } else {
+            self.birth[count]
+        }
+    }
+}
+
+fn parse_counts(digits: &str, s: &str) -> Result<[bool; 9], String> {
+    let mut counts = [false; 9];
+    for d in digits.chars() {
+        match d.to_digit(10) {
+            Some(n) if n <= 8 => counts[n as usize] = true,
+            _ => return Err(format!("invalid rule: {}", s)),
+        }
+    }
+    Ok(counts)
+}
+
+impl FromStr for Rule {
+    type Err = String;
+
+    // Accepts "B3/S23" in either order, and the traditional "23/3" survival/birth form.
+    fn from_str(s: &str) -> Result<Rule, String> {
+        let parts: Vec<&str> = s.trim().split('/').collect();
+        if parts.len() != 2 {
+            return Err(format!("invalid rule: {}", s));
+        }
+
+        let mut birth = None;
+        let mut survival = None;
+        for part in &parts {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some('B') | Some('b') => birth = Some(parse_counts(chars.as_str(), s)?),
+                Some('S') | Some('s') => survival = Some(parse_counts(chars.as_str(), s)?),
+                _ => {},
+            }
+        }
+
+        match (birth, survival) {
+            (Some(birth), Some(survival)) => Ok(Rule { birth: birth, survival: survival }),
+            (None, None) => Ok(Rule {
+                birth: parse_counts(parts[1], s)?,
+                survival: parse_counts(parts[0], s)?,
+            }),
+            _ => Err(format!("invalid rule: {}", s)),
+        }
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "B")?;
+        for n in 0..9 {
+            if self.birth[n] {
+                write!(f, "{}", n)?;
+            }
+        }
+        write!(f, "/S")?;
+        for n in 0..9 {
+            if self.survival[n] {
+                write!(f, "{}", n)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_from_str() {
+    assert_eq!(Ok(Rule::conway()), "B3/S23".parse());
+    assert_eq!(Ok(Rule::conway()), "s23/b3".parse());
+    assert_eq!(Ok(Rule::conway()), "23/3".parse());
+
+    let seeds: Rule = "B2/S".parse().unwrap();
+    assert_eq!([false, false, true, false, false, false, false, false, false], seeds.birth);
+    assert_eq!([false; 9], seeds.survival);
+
+    assert!("B39/S23".parse::<Rule>().is_err());
+    assert!("B3S23".parse::<Rule>().is_err());
+    assert!("B3/X23".parse::<Rule>().is_err());
+}
+
+#[test]
+fn test_to_string() {
+    assert_eq!("B3/S23", Rule::conway().to_string());
+    assert_eq!("B3678/S34678", "34678/3678".parse::<Rule>().unwrap().to_string());
+}
+
+#[test]
+fn test_next_state() {
+    let rule = Rule::conway();
+
+    assert!(rule.next_state(false, 3));
+    assert!(!rule.next_state(false, 2));
+    assert!(rule.next_state(true, 2));
+    assert!(!rule.next_state(true, 4));
+}
@@ -0,0 +1,530 @@
+use std::f64::consts::PI;
+use cells::*;
+use raster::*;
+
+// Line segments a full turn of an elliptical arc is flattened into.
+const ARC_STEPS: f64 = 32.0;
+
+// The area to draw: min x, min y, width and height.
+type ViewBox = (f64, f64, f64, f64);
+
+// Paths with the fill rule each is filled with.
+type Shapes = Vec<(Path, FillRule)>;
+
+// The filled shapes of an SVG path or document, in SVG user units.
+pub struct Svg {
+    view_box: ViewBox,
+    shapes: Shapes,
+}
+
+impl Svg {
+    // Reads either the `d` attribute of a path, e.g. "M 0 0 L 10 5 L 0 10 Z",
+    // or a simple SVG document. Documents may contain <path>, <polygon>,
+    // <polyline>, <rect>, <circle> and <ellipse> elements; transforms, strokes
+    // and styles other than `fill="none"` and `fill-rule` are ignored. Shapes
+    // without a fill rule of their own use `fill_rule`.
+    pub fn parse(s: &str, fill_rule: FillRule) -> Result<Svg, String> {
+        let s = s.trim();
+        let (view_box, shapes) = if s.starts_with('<') {
+            parse_document(s, fill_rule)?
+        } else {
+            let mut path = Path::new();
+            parse_path(s, &mut path)?;
+            (None, vec![(path, fill_rule)])
+        };
+
+        let view_box = match view_box {
+            Some(view_box) => view_box,
+            None => bounds(&shapes).ok_or("the SVG has nothing to draw".to_string())?,
+        };
+        if !(view_box.2 > 0.0 && view_box.3 > 0.0) {
+            return Err("the SVG has no area to draw".to_string());
+        }
+        // Finite numbers can still add up to infinity.
+        let (x, y, w, h) = view_box;
+        let finite = [x, y, w, h].iter().all(|n| n.is_finite()) && shapes.iter()
+            .flat_map(|(path, _)| path.edges.iter())
+            .all(|&(x0, y0, x1, y1)| [x0, y0, x1, y1].iter().all(|n| n.is_finite()));
+        if !finite {
+            return Err("the SVG has coordinates too large to draw".to_string());
+        }
+        Ok(Svg {
+            view_box: view_box,
+            shapes: shapes,
+        })
+    }
+
+    fn scale(&self, font_size: usize) -> f64 {
+        font_size as f64 / self.view_box.2.max(self.view_box.3)
+    }
+
+    // How wide the drawing is at the font size, in cells.
+    pub fn width(&self, font_size: usize) -> usize {
+        (self.view_box.2 * self.scale(font_size)).round() as usize
+    }
+
+    // Draws the view box on a `font_size` square, as large as it fits, from
+    // the left edge and centred vertically.
+    pub fn rasterize(&self, font_size: usize) -> Cells {
+        let scale = self.scale(font_size);
+        let (x, y, _, height) = self.view_box;
+        let top = (font_size as f64 - height * scale) / 2.0;
+        let paths: Vec<Path> = self.shapes.iter()
+            .map(|(path, _)| path.transformed(scale, -x * scale, top - y * scale))
+            .collect();
+        let shapes: Vec<(&Path, FillRule)> = paths.iter()
+            .zip(self.shapes.iter())
+            .map(|(path, &(_, fill_rule))| (path, fill_rule))
+            .collect();
+        rasterize(&shapes, font_size, font_size, 0.5)
+    }
+}
+
+fn bounds(shapes: &[(Path, FillRule)]) -> Option<ViewBox> {
+    let mut points = shapes.iter()
+        .flat_map(|(path, _)| path.edges.iter())
+        .flat_map(|&(x0, y0, x1, y1)| vec![(x0, y0), (x1, y1)]);
+    let (x, y) = points.next()?;
+    let (min_x, min_y, max_x, max_y) = points.fold((x, y, x, y), |(a, b, c, d), (x, y)| {
+        (a.min(x), b.min(y), c.max(x), d.max(y))
+    });
+    Some((min_x, min_y, max_x - min_x, max_y - min_y))
+}
+
+fn parse_document(s: &str, default_fill_rule: FillRule) -> Result<(Option<ViewBox>, Shapes), String> {
+    let mut view_box = None;
+    let mut shapes = Vec::new();
+
+    for tag in s.split('<').skip(1) {
+        let tag = tag.split('>').next().unwrap_or("").trim_end_matches('/');
+        let name = tag.split(|c: char| c.is_whitespace()).next().unwrap_or("");
+        let attribute = |key: &str| attribute(tag, key);
+
+        if name == "svg" {
+            if view_box.is_none() {
+                view_box = match attribute("viewBox") {
+                    Some(value) => {
+                        let numbers = parse_numbers(&value)?;
+                        if numbers.len() != 4 {
+                            return Err(format!("invalid viewBox: {}", value));
+                        }
+                        Some((numbers[0], numbers[1], numbers[2], numbers[3]))
+                    },
+                    None => match (attribute("width"), attribute("height")) {
+                        (Some(w), Some(h)) => Some((0.0, 0.0, parse_length(&w)?, parse_length(&h)?)),
+                        _ => None,
+                    },
+                };
+            }
+            continue;
+        }
+
+        let mut path = Path::new();
+        let number = |key: &str| attribute(key).map_or(Ok(0.0), |v| parse_length(&v));
+        match name {
+            "path" => parse_path(&attribute("d").unwrap_or_default(), &mut path)?,
+            "polygon" | "polyline" => {
+                let numbers = parse_numbers(&attribute("points").unwrap_or_default())?;
+                for (i, point) in numbers.chunks(2).filter(|p| p.len() == 2).enumerate() {
+                    if i == 0 {
+                        path.move_to(point[0], point[1]);
+                    } else {
+                        path.line_to(point[0], point[1]);
+                    }
+                }
+            },
+            "rect" => {
+                let (x, y) = (number("x")?, number("y")?);
+                let (w, h) = (number("width")?, number("height")?);
+                path.move_to(x, y);
+                path.line_to(x + w, y);
+                path.line_to(x + w, y + h);
+                path.line_to(x, y + h);
+            },
+            "circle" | "ellipse" => {
+                let (cx, cy) = (number("cx")?, number("cy")?);
+                let (rx, ry) = if name == "circle" {
+                    (number("r")?, number("r")?)
+                } else {
+                    (number("rx")?, number("ry")?)
+                };
+                path.move_to(cx + rx, cy);
+                arc_to(&mut path, (cx + rx, cy), (rx, ry), 0.0, (false, true), (cx - rx, cy));
+                arc_to(&mut path, (cx - rx, cy), (rx, ry), 0.0, (false, true), (cx + rx, cy));
+            },
+            _ => continue,
+        }
+        path.close();
+
+        let style = attribute("style").unwrap_or_default().replace(' ', "");
+        if attribute("fill").is_some_and(|f| f == "none") || style.contains("fill:none") {
+            continue;
+        }
+        let fill_rule = match attribute("fill-rule") {
+            Some(value) => value.parse()?,
+            None if style.contains("fill-rule:evenodd") => FillRule::EvenOdd,
+            None if style.contains("fill-rule:nonzero") => FillRule::NonZero,
+            None => default_fill_rule,
+        };
+        shapes.push((path, fill_rule));
+    }
+
+    Ok((view_box, shapes))
+}
+
+// The value of `key="..."` or `key='...'` in the inside of a tag.
+fn attribute(tag: &str, key: &str) -> Option<String> {
+    let mut rest = tag;
+    while let Some(i) = rest.find(key) {
+        let before = rest[..i].chars().last();
+        let after = rest[i + key.len()..].trim_start();
+        rest = &rest[i + key.len()..];
+        if !before.is_some_and(|c| c.is_whitespace()) || !after.starts_with('=') {
+            continue;
+        }
+        let value = after[1..].trim_start();
+        let quote = value.chars().next()?;
+        if quote != '"' && quote != '\'' {
+            return None;
+        }
+        return value[1..].split(quote).next().map(|v| v.to_string());
+    }
+    None
+}
+
+// A length such as "24" or "24px"; other units are taken as user units.
+fn parse_length(s: &str) -> Result<f64, String> {
+    let number = s.trim().trim_end_matches(|c: char| c.is_ascii_alphabetic() || c == '%');
+    match number.parse::<f64>() {
+        Ok(n) if n.is_finite() => Ok(n),
+        _ => Err(format!("invalid length: {}", s)),
+    }
+}
+
+fn parse_numbers(s: &str) -> Result<Vec<f64>, String> {
+    let mut tokens = Tokens::new(s);
+    let mut numbers = Vec::new();
+    while tokens.at_number() {
+        numbers.push(tokens.number()?);
+    }
+    tokens.skip();
+    if tokens.i < tokens.s.len() {
+        return Err(format!("invalid list of numbers: {}", s));
+    }
+    Ok(numbers)
+}
+
+// Reads the numbers and commands of path data.
+struct Tokens<'a> {
+    s: &'a [u8],
+    i: usize,
+}
+
+impl<'a> Tokens<'a> {
+    fn new(s: &'a str) -> Tokens<'a> {
+        Tokens {
+            s: s.as_bytes(),
+            i: 0,
+        }
+    }
+
+    fn skip(&mut self) {
+        while self.i < self.s.len() && (self.s[self.i].is_ascii_whitespace() || self.s[self.i] == b',') {
+            self.i += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip();
+        self.s.get(self.i).cloned()
+    }
+
+    fn at_number(&mut self) -> bool {
+        match self.peek() {
+            Some(c) => c.is_ascii_digit() || c == b'.' || c == b'-' || c == b'+',
+            None => false,
+        }
+    }
+
+    fn command(&mut self) -> Option<u8> {
+        match self.peek() {
+            Some(c) if c.is_ascii_alphabetic() => {
+                self.i += 1;
+                Some(c)
+            },
+            _ => None,
+        }
+    }
+
+    // Numbers may run together, as in "1.5.5" or "2-3".
+    fn number(&mut self) -> Result<f64, String> {
+        self.skip();
+        let start = self.i;
+        let mut seen_dot = false;
+        let mut seen_exponent = false;
+        while self.i < self.s.len() {
+            let c = self.s[self.i];
+            let exponent = c == b'e' || c == b'E';
+            let accepted = if c == b'.' {
+                !seen_dot && !seen_exponent
+            } else if exponent {
+                !seen_exponent && self.i > start
+            } else if c == b'-' || c == b'+' {
+                self.i == start || self.s[self.i - 1] == b'e' || self.s[self.i - 1] == b'E'
+            } else {
+                c.is_ascii_digit()
+            };
+            if !accepted {
+                break;
+            }
+            seen_dot |= c == b'.';
+            seen_exponent |= exponent;
+            self.i += 1;
+        }
+        let number = String::from_utf8_lossy(&self.s[start..self.i]);
+        match number.parse::<f64>() {
+            Ok(n) if n.is_finite() => Ok(n),
+            _ => Err(format!("expected a number in path data at {}", start)),
+        }
+    }
+
+    // Arc flags are a single digit, and may run into the next number.
+    fn flag(&mut self) -> Result<bool, String> {
+        match self.peek() {
+            Some(b'0') => { self.i += 1; Ok(false) },
+            Some(b'1') => { self.i += 1; Ok(true) },
+            _ => Err(format!("expected a flag in path data at {}", self.i)),
+        }
+    }
+}
+
+// Adds the subpaths of path data, the `d` attribute of a <path>.
+fn parse_path(d: &str, path: &mut Path) -> Result<(), String> {
+    let mut tokens = Tokens::new(d);
+    let mut command = None;
+    let (mut x, mut y) = (0.0, 0.0);
+    let mut start = (0.0, 0.0);
+    // The last control point of a cubic or quadratic curve, for S and T.
+    let mut last_cubic = None;
+    let mut last_quad = None;
+
+    while tokens.peek().is_some() {
+        if let Some(c) = tokens.command() {
+            command = Some(c);
+        } else if command.is_none() || !tokens.at_number() {
+            return Err(format!("invalid path data at {}", tokens.i));
+        }
+        let c = command.unwrap();
+        let relative = c.is_ascii_lowercase();
+        let (ox, oy) = if relative { (x, y) } else { (0.0, 0.0) };
+        let (cubic, quad) = (last_cubic.take(), last_quad.take());
+
+        match c.to_ascii_uppercase() {
+            b'M' => {
+                x = ox + tokens.number()?;
+                y = oy + tokens.number()?;
+                path.move_to(x, y);
+                start = (x, y);
+                // Further coordinates are lines.
+                command = Some(if relative { b'l' } else { b'L' });
+            },
+            b'L' => {
+                x = ox + tokens.number()?;
+                y = oy + tokens.number()?;
+                path.line_to(x, y);
+            },
+            b'H' => {
+                x = ox + tokens.number()?;
+                path.line_to(x, y);
+            },
+            b'V' => {
+                y = oy + tokens.number()?;
+                path.line_to(x, y);
+            },
+            b'C' | b'S' => {
+                let (x1, y1) = if c.eq_ignore_ascii_case(&b'C') {
+                    (ox + tokens.number()?, oy + tokens.number()?)
+                } else {
+                    cubic.map_or((x, y), |(cx, cy)| (2.0 * x - cx, 2.0 * y - cy))
+                };
+                let (x2, y2) = (ox + tokens.number()?, oy + tokens.number()?);
+                x = ox + tokens.number()?;
+                y = oy + tokens.number()?;
+                path.cubic_to(x1, y1, x2, y2, x, y);
+                last_cubic = Some((x2, y2));
+            },
+            b'Q' | b'T' => {
+                let (x1, y1) = if c.eq_ignore_ascii_case(&b'Q') {
+                    (ox + tokens.number()?, oy + tokens.number()?)
+                } else {
+                    quad.map_or((x, y), |(qx, qy)| (2.0 * x - qx, 2.0 * y - qy))
+                };
+                x = ox + tokens.number()?;
+                y = oy + tokens.number()?;
+                path.quad_to(x1, y1, x, y);
+                last_quad = Some((x1, y1));
+            },
+            b'A' => {
+                let radii = (tokens.number()?, tokens.number()?);
+                let rotation = tokens.number()?;
+                let flags = (tokens.flag()?, tokens.flag()?);
+                let end = (ox + tokens.number()?, oy + tokens.number()?);
+                arc_to(path, (x, y), radii, rotation, flags, end);
+                x = end.0;
+                y = end.1;
+            },
+            b'Z' => {
+                path.close();
+                x = start.0;
+                y = start.1;
+                command = None;
+            },
+            _ => return Err(format!("unknown path command: {}", c as char)),
+        }
+    }
+    Ok(())
+}
+
+// Flattens an elliptical arc from `from` to `to`, following the endpoint to
+// centre conversion in the SVG specification. The flags are the large arc
+// flag and the sweep flag.
+fn arc_to(path: &mut Path, from: (f64, f64), radii: (f64, f64), rotation: f64, flags: (bool, bool), to: (f64, f64)) {
+    let (x1, y1) = from;
+    let (x2, y2) = to;
+    let (mut rx, mut ry) = (radii.0.abs(), radii.1.abs());
+    let (large_arc, sweep) = flags;
+    if from == to {
+        return;
+    }
+    if rx == 0.0 || ry == 0.0 {
+        path.line_to(x2, y2);
+        return;
+    }
+
+    let (sin, cos) = rotation.to_radians().sin_cos();
+    let (dx, dy) = ((x1 - x2) / 2.0, (y1 - y2) / 2.0);
+    let (x1p, y1p) = (cos * dx + sin * dy, -sin * dx + cos * dy);
+    // Radii too small to reach the end are scaled up.
+    let lambda = (x1p * x1p) / (rx * rx) + (y1p * y1p) / (ry * ry);
+    if lambda > 1.0 {
+        rx *= lambda.sqrt();
+        ry *= lambda.sqrt();
+    }
+
+    let numerator = rx * rx * ry * ry - rx * rx * y1p * y1p - ry * ry * x1p * x1p;
+    let denominator = rx * rx * y1p * y1p + ry * ry * x1p * x1p;
+    let sign = if large_arc == sweep { -1.0 } else { 1.0 };
+    let coefficient = sign * (numerator / denominator).max(0.0).sqrt();
+    let (cxp, cyp) = (coefficient * rx * y1p / ry, -coefficient * ry * x1p / rx);
+    let cx = cos * cxp - sin * cyp + (x1 + x2) / 2.0;
+    let cy = sin * cxp + cos * cyp + (y1 + y2) / 2.0;
+
+    let theta = ((y1p - cyp) / ry).atan2((x1p - cxp) / rx);
+    let mut delta = ((-y1p - cyp) / ry).atan2((-x1p - cxp) / rx) - theta;
+    if sweep && delta < 0.0 {
+        delta += 2.0 * PI;
+    } else if !sweep && delta > 0.0 {
+        delta -= 2.0 * PI;
+    }
+
+    let steps = (delta.abs() / (2.0 * PI) * ARC_STEPS).ceil().max(1.0) as usize;
+    for i in 1..steps {
+        let (s, c) = (theta + delta * i as f64 / steps as f64).sin_cos();
+        path.line_to(cx + rx * c * cos - ry * s * sin, cy + rx * c * sin + ry * s * cos);
+    }
+    path.line_to(x2, y2);
+}
+
+#[test]
+fn test_parse_path() {
+    // The same 4 by 4 square with absolute and relative commands.
+    let absolute = Svg::parse("M 1 1 L 5 1 L 5 5 L 1 5 Z", FillRule::NonZero).unwrap();
+    let relative = Svg::parse("m1,1h4v4H1z", FillRule::NonZero).unwrap();
+
+    assert_eq!((1.0, 1.0, 4.0, 4.0), absolute.view_box);
+    assert_eq!(absolute.view_box, relative.view_box);
+    assert_eq!(64, absolute.rasterize(8).count_alive());
+    assert_eq!(absolute.rasterize(8), relative.rasterize(8));
+
+    let curves = Svg::parse("M0 0C0 4 4 4 4 0S8-4 8 0Q10 2 12 0T16 0z", FillRule::NonZero).unwrap();
+    assert_eq!(16.0, curves.view_box.2);
+    assert!(Svg::parse("M 0 0 L 1", FillRule::NonZero).is_err());
+    assert!(Svg::parse("0 0 L 1 1", FillRule::NonZero).is_err());
+    assert!(Svg::parse("M 0 0 X 1 1", FillRule::NonZero).is_err());
+    assert!(Svg::parse("", FillRule::NonZero).is_err());
+}
+
+#[test]
+fn test_numbers() {
+    let mut tokens = Tokens::new("1.5.5-2e1,+3 0");
+    let numbers: Vec<f64> = (0..5).map(|_| tokens.number().unwrap()).collect();
+    assert_eq!(vec![1.5, 0.5, -20.0, 3.0, 0.0], numbers);
+    assert_eq!(Ok(vec![1.0, 2.0, 3.5]), parse_numbers(" 1,2 3.5 "));
+    assert!(parse_numbers("1 x").is_err());
+    assert!(parse_numbers("1e999").is_err());
+    assert!(parse_length("1e999px").is_err());
+}
+
+#[test]
+fn test_parse_rejects_infinity() {
+    let svg = "<svg viewBox='0 0 10 10'><path d='M1e999 0L1e999 10L0 10z'/></svg>";
+    assert!(Svg::parse(svg, FillRule::NonZero).is_err());
+    assert!(Svg::parse("M1e308 0L1e308 10L-1e308 10z", FillRule::NonZero).is_err());
+    assert!(Svg::parse("<svg viewBox='0 0 1e999 10'><rect width='1' height='1'/></svg>", FillRule::NonZero).is_err());
+}
+
+#[test]
+fn test_arc() {
+    // A circle of radius 10 from two half arcs, with the flags run together.
+    let circle = Svg::parse("M0 10a10 10 0 1120 0a10 10 0 11-20 0z", FillRule::NonZero).unwrap();
+    assert_eq!((0.0, 0.0), (circle.view_box.0.round(), circle.view_box.1.round()));
+    assert_eq!((20.0, 20.0), (circle.view_box.2.round(), circle.view_box.3.round()));
+
+    let cells = circle.rasterize(20);
+    let area = cells.count_alive() as f64;
+    assert!((area - 100.0 * PI).abs() < 10.0, "{}", area);
+    assert!(cells.is_alive(10, 10) && !cells.is_alive(0, 0));
+}
+
+#[test]
+fn test_parse_document() {
+    let svg = r#"<?xml version="1.0"?>
+        <svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 12 6">
+          <!-- Two nested squares -->
+          <path fill-rule="evenodd" d="M0 0h6v6H0z M2 2h2v2H2z"/>
+          <rect x="6" y="0" width="6" height="6" style="fill: none"/>
+          <polygon points="6,0 12,0 12,6"/>
+        </svg>"#;
+    let svg = Svg::parse(svg, FillRule::NonZero).unwrap();
+    let cells = svg.rasterize(12);
+
+    assert_eq!((0.0, 0.0, 12.0, 6.0), svg.view_box);
+    assert_eq!(12, svg.width(12));
+    // The view box is centred vertically, 6 by 12 cells from the top.
+    assert!(cells.is_alive(0, 3) && cells.is_alive(0, 8) && !cells.is_alive(0, 2));
+    assert!(!cells.is_alive(2, 5) && cells.is_alive(1, 4));
+    assert!(cells.is_alive(11, 4) && !cells.is_alive(6, 8));
+
+    let svg = Svg::parse("<svg width='4px' height='4px'><circle cx='2' cy='2' r='2'/></svg>", FillRule::NonZero).unwrap();
+    assert_eq!((0.0, 0.0, 4.0, 4.0), svg.view_box);
+    assert!(Svg::parse("<svg viewBox='0 0 4'><rect width='1' height='1'/></svg>", FillRule::NonZero).is_err());
+    assert!(Svg::parse("<svg><g/></svg>", FillRule::NonZero).is_err());
+}
+
+#[test]
+fn test_fill_rule_default() {
+    let d = "M0 0h6v6H0z M2 2h2v2H2z";
+    let nonzero = Svg::parse(d, FillRule::NonZero).unwrap();
+    let evenodd = Svg::parse(d, FillRule::EvenOdd).unwrap();
+
+    assert_eq!(36, nonzero.rasterize(6).count_alive());
+    assert_eq!(32, evenodd.rasterize(6).count_alive());
+}
+
+#[test]
+fn test_attribute() {
+    let tag = "rect x='1' width=\"2\" data-x=\"3\"";
+    assert_eq!(Some("1".to_string()), attribute(tag, "x"));
+    assert_eq!(Some("2".to_string()), attribute(tag, "width"));
+    assert_eq!(None, attribute(tag, "height"));
+}
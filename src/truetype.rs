@@ -0,0 +1,134 @@
+use ttf_parser::{Face, GlyphId, OutlineBuilder};
+use mold::*;
+use resample::*;
+use raster::*;
+
+// A TrueType or OpenType font, rasterized straight into molds at the font
+// size. The line from the ascender to the descender spans the whole mold.
+pub struct TrueTypeFont {
+    pub data: Vec<u8>,
+    // Cells covered by at least this fraction of their area are alive.
+    pub threshold: f64,
+}
+
+impl TrueTypeFont {
+    pub fn from_bytes(bytes: &[u8]) -> Result<TrueTypeFont, String> {
+        Face::parse(bytes, 0).map_err(|e| format!("invalid TrueType font: {}", e))?;
+        Ok(TrueTypeFont {
+            data: bytes.to_vec(),
+            threshold: 0.5,
+        })
+    }
+
+    // Parsing only reads the table directory, so it is cheap to redo for
+    // every glyph rather than keep a face borrowing `data` around.
+    fn face<'a>(&'a self) -> Face<'a> {
+        Face::parse(&self.data, 0).unwrap()
+    }
+}
+
+fn scale(face: &Face, font_size: usize) -> f64 {
+    font_size as f64 / (face.ascender() as f64 - face.descender() as f64).max(1.0)
+}
+
+impl Typeface for TrueTypeFont {
+    // Outlines are rasterized at the font size, so there is nothing to resample.
+    fn mold(&self, c: char, font_size: usize, _: Resampling) -> Mold {
+        let face = self.face();
+        let mut builder = Builder {
+            path: Path::new(),
+            scale: scale(&face, font_size),
+            ascender: face.ascender() as f64,
+        };
+        if let Some(id) = face.glyph_index(c) {
+            face.outline_glyph(id, &mut builder);
+        }
+        builder.path.close();
+
+        Mold {
+            font_size: font_size,
+            target: rasterize(&[(&builder.path, FillRule::NonZero)], font_size, font_size, self.threshold),
+        }
+    }
+
+    fn advance(&self, c: char, next: Option<char>, font_size: usize) -> usize {
+        let face = self.face();
+        let id = match face.glyph_index(c) {
+            Some(id) => id,
+            None => return font_size / 3,
+        };
+        let width = face.glyph_hor_advance(id).unwrap_or(0) as f64;
+        let kerning = next.and_then(|n| face.glyph_index(n))
+            .and_then(|n| kerning(&face, id, n))
+            .unwrap_or(0) as f64;
+        ((width + kerning) * scale(&face, font_size)).round().max(0.0) as usize
+    }
+}
+
+fn kerning(face: &Face, left: GlyphId, right: GlyphId) -> Option<i16> {
+    face.tables().kern?.subtables.into_iter()
+        .filter(|s| s.horizontal && !s.variable)
+        .filter_map(|s| s.glyphs_kerning(left, right))
+        .next()
+}
+
+// Converts outlines from font units, with y pointing up, into cells.
+struct Builder {
+    path: Path,
+    scale: f64,
+    ascender: f64,
+}
+
+impl Builder {
+    fn point(&self, x: f32, y: f32) -> (f64, f64) {
+        (x as f64 * self.scale, (self.ascender - y as f64) * self.scale)
+    }
+}
+
+impl OutlineBuilder for Builder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        let (x, y) = self.point(x, y);
+        self.path.move_to(x, y);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let (x, y) = self.point(x, y);
+        self.path.line_to(x, y);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let (x1, y1) = self.point(x1, y1);
+        let (x, y) = self.point(x, y);
+        self.path.quad_to(x1, y1, x, y);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let (x1, y1) = self.point(x1, y1);
+        let (x2, y2) = self.point(x2, y2);
+        let (x, y) = self.point(x, y);
+        self.path.cubic_to(x1, y1, x2, y2, x, y);
+    }
+
+    fn close(&mut self) {
+        self.path.close();
+    }
+}
+
+#[test]
+fn test_builder() {
+    let mut builder = Builder {
+        path: Path::new(),
+        scale: 0.01,
+        ascender: 800.0,
+    };
+    builder.move_to(100.0, 0.0);
+    builder.line_to(500.0, 0.0);
+    builder.line_to(500.0, 800.0);
+    builder.line_to(100.0, 800.0);
+    builder.close();
+    let cells = rasterize(&[(&builder.path, FillRule::NonZero)], 10, 10, 0.5);
+
+    assert_eq!(32, cells.count_alive());
+    assert!(cells.is_alive(1, 0) && cells.is_alive(4, 7) && !cells.is_alive(5, 7));
+    assert!(TrueTypeFont::from_bytes(b"OTTO").is_err());
+}
@@ -0,0 +1,146 @@
+use cells::*;
+use compound::*;
+use evolve::*;
+use primes::*;
+use rule::*;
+
+// A prime placed on the board. Coordinates may lie outside the board, where
+// they wrap around or get clipped depending on the topology.
+pub type Placement = (i64, i64, Prime<'static>);
+
+// Removes primes that do not go through their phases undisturbed on the
+// composed board, e.g. because a neighbouring glyph is too close or the board
+// edge clips them, and redraws `cells` with the primes that are left. Returns
+// the number of primes removed.
+pub fn repair(cells: &mut Cells, rule: &Rule, placements: &mut Vec<Placement>) -> usize {
+    let mut removed = 0;
+    while let Some(i) = most_unstable(cells, rule, placements) {
+        placements.remove(i);
+        removed += 1;
+    }
+
+    *cells = render(cells, placements, 0);
+    removed
+}
+
+// Simulates the primes for a full period and returns the index of the prime
+// with the most cells around it that deviate from the phase they should be in,
+// or None if every prime is fine. The board is deterministic, so once it has
+// gone through a full period unchanged it repeats itself forever.
+pub fn most_unstable(board: &Cells, rule: &Rule, placements: &[Placement]) -> Option<usize> {
+    let period = placements.iter().fold(1, |acc, (_, _, p)| lcm(acc, p.period));
+    let mut cells = render(board, placements, 0);
+
+    for phase in 1..period + 1 {
+        cells = next(&cells, rule);
+        let expected = render(board, placements, phase);
+        if cells == expected {
+            continue;
+        }
+
+        // Every cell that deviates first is next to some prime, so this
+        // always picks one. Ties go to the prime placed last.
+        let deviations: Vec<usize> = placements.iter()
+            .map(|placement| deviations_near(&cells, &expected, placement))
+            .collect();
+        return (0..placements.len()).max_by_key(|&i| deviations[i]);
+    }
+    None
+}
+
+// The primes drawn in the given phase on an empty board like `board`.
+fn render(board: &Cells, placements: &[Placement], phase: usize) -> Cells {
+    let mut cells = Cells::new(board.size_x, board.size_y);
+    cells.topology = board.topology;
+    for &(x, y, ref p) in placements {
+        let mask = p.phase(phase);
+        for x2 in 0..p.exclusive_size {
+            for y2 in 0..p.exclusive_size {
+                if mask[x2][y2] == 0 {
+                    continue;
+                }
+                if let Some((cx, cy)) = cells.locate(x + x2 as i64, y + y2 as i64) {
+                    cells.make_alive(cx, cy);
+                }
+            }
+        }
+    }
+    cells
+}
+
+// Counts the cells within one cell of the exclusive area of the prime where
+// `cells` differs from `expected`.
+fn deviations_near(cells: &Cells, expected: &Cells, placement: &Placement) -> usize {
+    let (x, y, ref p) = *placement;
+    let size = p.exclusive_size as i64;
+    let mut count = 0;
+    for x in x - 1..x + size + 1 {
+        for y in y - 1..y + size + 1 {
+            if let Some((cx, cy)) = cells.locate(x, y) {
+                if cells.is_alive(cx, cy) != expected.is_alive(cx, cy) {
+                    count += 1;
+                }
+            }
+        }
+    }
+    count
+}
+
+#[cfg(test)]
+fn board(topology: Topology) -> Cells {
+    let mut cells = Cells::new(30, 20);
+    cells.topology = topology;
+    cells
+}
+
+#[test]
+fn test_repair_keeps_isolated_primes() {
+    let mut cells = board(Topology::Dead);
+    let mut placements = vec![(0, 0, BLOCK), (4, 0, BLINKER), (9, 0, TOAD), (0, 10, CLOCK), (6, 10, BEACON)];
+
+    assert_eq!(0, repair(&mut cells, &Rule::conway(), &mut placements));
+    assert_eq!(5, placements.len());
+    assert_eq!(4 + 3 + 6 + 6 + 6, cells.count_alive());
+}
+
+#[test]
+fn test_repair_removes_interacting_primes() {
+    let mut cells = board(Topology::Dead);
+    // The blinkers' boxes overlap, so their horizontal phases collide.
+    let mut placements = vec![(0, 10, BLOCK), (10, 0, BLINKER), (13, 0, BLINKER)];
+
+    assert_eq!(1, repair(&mut cells, &Rule::conway(), &mut placements));
+    assert_eq!(vec![(0, 10, BLOCK), (10, 0, BLINKER)], placements);
+    assert_eq!(4 + 3, cells.count_alive());
+}
+
+#[test]
+fn test_repair_removes_clipped_primes() {
+    let mut placements = vec![(-2, 5, BLINKER), (10, 5, BLOCK)];
+
+    let mut cells = board(Topology::Dead);
+    assert_eq!(1, repair(&mut cells, &Rule::conway(), &mut placements));
+    assert_eq!(vec![(10, 5, BLOCK)], placements);
+
+    let mut placements = vec![(-2, 5, BLINKER), (10, 5, BLOCK)];
+    let mut cells = board(Topology::Torus);
+    assert_eq!(0, repair(&mut cells, &Rule::conway(), &mut placements));
+}
+
+#[test]
+fn test_repaired_board_is_periodic() {
+    let mut cells = board(Topology::Torus);
+    let mut placements = vec![];
+    for i in 0..12 {
+        let p = PRIMES[i % PRIMES.len()].clone();
+        placements.push(((i * 7 % 30) as i64, (i * 5 % 20) as i64, p));
+    }
+    repair(&mut cells, &Rule::conway(), &mut placements);
+
+    let initial = cells.clone();
+    for _ in 0..2 {
+        cells = next(&cells, &Rule::conway());
+    }
+    assert_eq!(initial, cells);
+    assert!(!placements.is_empty());
+}